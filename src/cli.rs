@@ -1,8 +1,14 @@
 use crate::config::Config;
+use crate::core::constraint::VersionConstraint;
+use crate::core::env::EnvAction;
 use crate::core::plugin::PluginRegistry;
+use crate::core::traits::ToolVersion;
 use crate::detect::JavaDetector;
 use crate::error::{JcvmError, Result};
+use crate::hook_env::{self, HookState, HOOK_STATE_ENV};
 use crate::plugins;
+use crate::project::tool_versions::{self, TOOL_VERSIONS_FILE};
+use crate::project::{self, Project, VersionRequest, PROJECT_FILE};
 use crate::shell::{generate_activation_script, Shell};
 use crate::tool_manager::{ActivationContext, ManagedInstallation, ToolManager};
 use crate::utils::{confirm, format_size, print_error, print_info, print_success, print_warning};
@@ -11,6 +17,8 @@ use clap::{Parser, Subcommand};
 use colored::*;
 use std::collections::BTreeMap;
 use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::path::Path;
 
 #[derive(Parser)]
 #[command(name = "jcvm")]
@@ -52,6 +60,139 @@ mod tests {
         assert_eq!(version, "20.10.0");
     }
 
+    #[test]
+    fn install_target_accepts_optional_tool() {
+        let args = vec!["java".to_string(), "corretto-17".to_string()];
+        assert_eq!(
+            Cli::install_target(None, &args),
+            ("java".to_string(), "corretto-17".to_string())
+        );
+
+        let args = vec!["20.10.0".to_string()];
+        assert_eq!(
+            Cli::install_target(Some("node"), &args),
+            ("node".to_string(), "20.10.0".to_string())
+        );
+    }
+
+    #[test]
+    fn install_without_version_keeps_the_tool_filter() {
+        let cli = Cli::try_parse_from(["jcvm", "install", "--tool", "node"]).expect("parse");
+        match cli.command {
+            Commands::Install { tool, version, .. } => {
+                assert_eq!(tool.as_deref(), Some("node"));
+                assert!(version.is_empty());
+            }
+            _ => panic!("expected install"),
+        }
+    }
+
+    #[test]
+    fn with_image_appends_java_image_type() {
+        assert_eq!(
+            Cli::with_image("java", "21", Some("jre")).unwrap(),
+            "21-jre"
+        );
+        assert_eq!(Cli::with_image("java", "21", Some("jdk")).unwrap(), "21");
+        assert_eq!(Cli::with_image("java", "21", None).unwrap(), "21");
+        assert!(Cli::with_image("java", "21", Some("server")).is_err());
+        assert!(Cli::with_image("node", "20", Some("jre")).is_err());
+    }
+
+    #[test]
+    fn shim_exec_passes_arguments_through() {
+        let cli = Cli::try_parse_from([
+            "jcvm",
+            "shim-exec",
+            "--tool",
+            "java",
+            "java",
+            "-version",
+            "--tool",
+        ])
+        .expect("parse");
+        match cli.command {
+            Commands::ShimExec { tool, name, args } => {
+                assert_eq!((tool.as_str(), name.as_str()), ("java", "java"));
+                assert_eq!(args, vec!["-version", "--tool"]);
+            }
+            _ => panic!("expected shim-exec"),
+        }
+    }
+
+    #[test]
+    fn shell_override_requires_a_version_unless_unsetting() {
+        assert!(Cli::try_parse_from(["jcvm", "shell", "java", "17"]).is_ok());
+        assert!(Cli::try_parse_from(["jcvm", "shell", "--unset"]).is_ok());
+        assert!(Cli::try_parse_from(["jcvm", "shell", "--unset", "java"]).is_ok());
+        assert!(Cli::try_parse_from(["jcvm", "shell", "java"]).is_err());
+        assert!(Cli::try_parse_from(["jcvm", "shell", "--unset", "java", "17"]).is_err());
+    }
+
+    #[test]
+    fn exec_splits_targets_from_the_command() {
+        let tools = vec!["java".to_string(), "node".to_string()];
+        let args = |args: &[&str]| args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+        let (targets, command) = Cli::split_exec_args(
+            &args(&["java@17", "node@20", "--", "./build.sh", "--"]),
+            &tools,
+        )
+        .unwrap();
+        assert_eq!(
+            targets,
+            vec![
+                ("java".to_string(), "17".to_string()),
+                ("node".to_string(), "20".to_string())
+            ]
+        );
+        assert_eq!(command, args(&["./build.sh", "--"]));
+
+        // Without `--`, only leading targets of known tools are split off
+        let (targets, command) =
+            Cli::split_exec_args(&args(&["node@20", "npm", "-v"]), &tools).unwrap();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(command, args(&["npm", "-v"]));
+        let (targets, command) =
+            Cli::split_exec_args(&args(&["ssh", "git@example.com"]), &tools).unwrap();
+        assert!(targets.is_empty());
+        assert_eq!(command, args(&["ssh", "git@example.com"]));
+
+        assert!(Cli::split_exec_args(&args(&["mvn", "--", "clean"]), &tools).is_err());
+    }
+
+    #[test]
+    fn hook_env_installs_with_output_on_stderr() {
+        let request = VersionRequest::new("node", "20", Path::new("/work/jcvm.toml"));
+        let cmd = Cli::install_to_stderr(Path::new("/usr/bin/jcvm"), &request);
+        assert_eq!(cmd.get_program(), "/usr/bin/jcvm");
+        assert_eq!(
+            cmd.get_args().collect::<Vec<_>>(),
+            vec!["install", "--tool", "node", "20"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn task_stage_stops_started_tasks_when_one_cannot_start() {
+        let run = "sleep 30".to_string();
+        let mut slow = std::process::Command::new("sh");
+        slow.arg("-c").arg(&run);
+        let missing = std::process::Command::new("/nonexistent/jcvm-task");
+
+        let started = std::time::Instant::now();
+        let err = Cli::spawn_tasks(
+            vec![
+                ("slow".to_string(), &run, slow),
+                ("missing".to_string(), &run, missing),
+            ],
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("'missing'"), "{}", err);
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    }
+
     #[test]
     fn parse_target_rejects_missing_version() {
         let err = Cli::parse_tool_target("node@").unwrap_err();
@@ -71,16 +212,28 @@ enum Commands {
         /// Show only LTS/stable versions (when applicable)
         #[arg(long)]
         lts: bool,
+
+        /// List every patch release of this major version, with release dates
+        #[arg(long, conflicts_with = "lts")]
+        major: Option<u32>,
     },
 
-    /// Install a tool version (Java, Node.js, Python, ...)
+    /// Install a tool version, or every tool the project declares (Java, Node.js, Python, ...)
     Install {
-        /// Tool to install (defaults to 'java' for backward compatibility)
-        #[arg(short, long, default_value = "java")]
-        tool: String,
+        /// Tool to install (defaults to 'java' when a version is given, for backward
+        /// compatibility; without a version, only the project's request for this tool)
+        #[arg(short, long)]
+        tool: Option<String>,
 
-        /// Version to install (e.g., 21, 17.0.10, 20.10.0)
-        version: String,
+        /// Version or constraint to install, optionally preceded by the tool
+        /// (e.g., 21, 17.0.10, ^20.10, ">=17 <22", lts, "java corretto-17", "java 24-ea").
+        /// Omit to install everything declared in jcvm.toml and version files
+        #[arg(value_name = "[TOOL] VERSION", num_args = 0..=2)]
+        version: Vec<String>,
+
+        /// Java image type to install (jdk, jre, debugimage, staticlibs, jdkfx, jrefx)
+        #[arg(long, value_name = "IMAGE")]
+        image: Option<String>,
 
         /// Force reinstall if already installed
         #[arg(short, long)]
@@ -99,14 +252,16 @@ enum Commands {
         all: bool,
     },
 
-    /// Use a specific tool version
+    /// Use a specific tool version, or the versions the project declares
     Use {
-        /// Tool to activate (defaults to 'java' for backward compatibility)
-        #[arg(short, long, default_value = "java")]
-        tool: String,
+        /// Tool to activate (defaults to 'java' for backward compatibility, or
+        /// every project tool when no version is given)
+        #[arg(short, long)]
+        tool: Option<String>,
 
-        /// Version to use
-        version: String,
+        /// Version or constraint to use (e.g., 21, 21.x, ~3.12).
+        /// Omit to use the versions from jcvm.toml and version files
+        version: Option<String>,
     },
 
     /// Show currently active version for a tool
@@ -126,8 +281,12 @@ enum Commands {
         #[arg(short, long, default_value = "java")]
         tool: String,
 
-        /// Version to set (omit to show current)
+        /// Version or constraint to set (omit to show current)
         version: Option<String>,
+
+        /// Write the version to an asdf-compatible .tool-versions file
+        #[arg(long)]
+        tool_versions: bool,
     },
 
     /// Create or show aliases for a tool
@@ -159,7 +318,47 @@ enum Commands {
 
     /// Install shell integration
     ShellInit {
-        /// Shell type (bash, zsh, fish, powershell)
+        /// Shell type (bash, zsh, fish, powershell, nu, elvish, xonsh)
+        #[arg(short, long)]
+        shell: Option<String>,
+
+        /// Replace the installed script with the one from this version
+        #[arg(long, conflicts_with_all = ["uninstall", "print"])]
+        update: bool,
+
+        /// Remove the script from the shell's config file
+        #[arg(long, conflicts_with = "print")]
+        uninstall: bool,
+
+        /// Print the script instead of installing it, e.g. `eval "$(jcvm shell-init --print bash)"`
+        #[arg(long, value_name = "SHELL", conflicts_with = "shell")]
+        print: Option<String>,
+    },
+
+    /// Use a tool version in this shell session only (other terminals keep theirs)
+    #[command(name = "shell")]
+    SessionShell {
+        /// Tool to override (e.g., java, node)
+        #[arg(required_unless_present = "unset")]
+        tool: Option<String>,
+
+        /// Installed version or constraint to use
+        #[arg(required_unless_present = "unset")]
+        version: Option<String>,
+
+        /// Clear the override of the tool, or of every tool when none is given
+        #[arg(long, conflicts_with = "version")]
+        unset: bool,
+
+        /// Shell to print commands for (set by the shell integration)
+        #[arg(short, long)]
+        shell: Option<String>,
+    },
+
+    /// Print commands that apply the current directory's tool versions to this shell session
+    /// (run by the shell integration on directory change)
+    HookEnv {
+        /// Shell type (bash, zsh, fish, powershell, nu, elvish, xonsh)
         #[arg(short, long)]
         shell: Option<String>,
     },
@@ -180,17 +379,29 @@ enum Commands {
         key: Option<String>,
     },
 
-    /// Run a command with a specific JDK version
+    /// Run a command with the given tool versions (e.g. `jcvm exec java@17 node@20 -- ./build.sh`),
+    /// or with the project's tools
     Exec {
-        /// Version to use
+        /// JDK version to use (shorthand for `java@<version>`)
         #[arg(short, long)]
-        version: String,
+        version: Option<String>,
 
-        /// Command and arguments to execute
+        /// Tool versions (`<tool>@<version>`), then `--` and the command with its arguments.
+        /// Without tool versions, the ones from jcvm.toml and version files are used.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         command: Vec<String>,
     },
 
+    /// Run tasks from the `[tasks]` table of jcvm.toml with the project's tools
+    Run {
+        /// Tasks to run, with their dependencies (lists the tasks when omitted)
+        tasks: Vec<String>,
+
+        /// Run one task at a time instead of running independent tasks in parallel
+        #[arg(long)]
+        sequential: bool,
+    },
+
     /// Detect existing Java installations on the system
     Detect {
         /// Tool to detect (defaults to all tools)
@@ -214,15 +425,49 @@ enum Commands {
         action: ToolCommands,
     },
 
+    /// Use the project's tool versions (jcvm.toml, .tool-versions, .sdkmanrc, ...)
+    Env {
+        #[command(subcommand)]
+        action: Option<EnvCommands>,
+    },
+
     /// Quickly switch tool versions using shorthand like java@21 or node@20
     Switch {
-        /// Target in the form `<tool>@<version>` (e.g., java@21, node@20.10.0)
+        /// Target in the form `<tool>@<version>` (e.g., java@21, node@20.10.0, node@^20)
         target: String,
 
         /// Install the version automatically if missing before switching
         #[arg(long)]
         install: bool,
     },
+
+    /// Regenerate the shims in ~/.jcvm/shims from the installed versions
+    Reshim,
+
+    /// Run an executable with the version selected for the current directory (used by shims)
+    #[command(hide = true)]
+    ShimExec {
+        /// Tool providing the executable
+        #[arg(long)]
+        tool: String,
+
+        /// Executable name (java, npm, python3, ...)
+        name: String,
+
+        /// Arguments passed to the executable
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum EnvCommands {
+    /// Install every tool version the project declares
+    Install {
+        /// Force reinstall of versions that are already installed
+        #[arg(short, long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -312,10 +557,13 @@ enum ToolCommands {
     },
 }
 
+/// A `<tool>@<version>` pair given on the command line
+type ToolTarget = (String, String);
+
 impl Cli {
     pub fn new(config: Config) -> Result<Self> {
         let mut cli = Self::parse();
-        let registry = plugins::load_builtin_plugins(&config)?;
+        let registry = plugins::load_plugins(&config)?;
         let tool_manager = ToolManager::new(config.clone(), registry.clone());
         cli.config = config;
         cli.registry = registry;
@@ -366,22 +614,53 @@ impl Cli {
 
     pub async fn run(self) -> Result<()> {
         match self.command {
-            Commands::ListRemote { ref tool, lts } => self.list_remote(tool, lts).await,
+            Commands::ListRemote {
+                ref tool,
+                lts,
+                major: Some(major),
+            } if !lts => self.list_remote_releases(tool, major).await,
+            Commands::ListRemote { ref tool, lts, .. } => self.list_remote(tool, lts).await,
+            Commands::Install {
+                ref tool,
+                ref version,
+                ref image,
+                force,
+            } if version.is_empty() => {
+                if image.is_some() {
+                    return Err(JcvmError::InvalidVersion(
+                        "--image requires a version".to_string(),
+                    ));
+                }
+                self.install_project(force, tool.as_deref()).await
+            }
             Commands::Install {
                 ref tool,
                 ref version,
+                ref image,
                 force,
-            } => self.install(tool, version, force).await,
+            } => {
+                let (tool, version) = Self::install_target(tool.as_deref(), version);
+                let version = Self::with_image(&tool, &version, image.as_deref())?;
+                self.install(&tool, &version, force).await
+            }
             Commands::List { ref tool, all } => self.list(tool, all),
             Commands::Use {
                 ref tool,
-                ref version,
-            } => self.use_version(tool, version).await,
+                version: Some(ref version),
+            } => {
+                self.use_version(tool.as_deref().unwrap_or("java"), version)
+                    .await
+            }
+            Commands::Use {
+                ref tool,
+                version: None,
+            } => self.use_project(tool.as_deref()).await,
             Commands::Current { ref tool, all } => self.current(tool, all),
             Commands::Local {
                 ref tool,
                 ref version,
-            } => self.local(tool, version.clone()).await,
+                tool_versions,
+            } => self.local(tool, version.clone(), tool_versions).await,
             Commands::Alias {
                 ref tool,
                 ref name,
@@ -392,21 +671,55 @@ impl Cli {
                 ref version,
                 yes,
             } => self.uninstall(tool, version, yes).await,
-            Commands::ShellInit { ref shell } => self.shell_init(shell.clone()),
+            Commands::ShellInit {
+                ref shell,
+                update,
+                uninstall,
+                ref print,
+            } => match print {
+                Some(print) => self.print_shell_init(print),
+                None if uninstall => self.shell_uninstall(shell.as_deref()),
+                None => self.shell_init(shell.as_deref(), update),
+            },
+            Commands::SessionShell {
+                ref tool,
+                ref version,
+                unset,
+                ref shell,
+            } => self.session_shell(
+                tool.as_deref(),
+                version.as_deref().filter(|_| !unset),
+                shell.as_deref(),
+            ),
+            Commands::HookEnv { ref shell } => self.hook_env(shell.as_deref()),
             Commands::Which => self.which(),
             Commands::Clean { all } => self.clean(all),
             Commands::Config { ref key } => self.show_config(key.clone()),
             Commands::Exec {
                 ref version,
                 ref command,
-            } => self.exec(version, command.clone()).await,
+            } => self.exec(version.as_deref(), command.clone()).await,
+            Commands::Run {
+                ref tasks,
+                sequential,
+            } => self.run_tasks(tasks, sequential),
             Commands::Detect { ref tool, import } => self.detect(tool.clone(), import).await,
             Commands::Import { ref path } => self.import(path),
             Commands::Tool { ref action } => self.handle_tool(action).await,
+            Commands::Env { ref action } => match action {
+                Some(EnvCommands::Install { force }) => self.install_project(*force, None).await,
+                None => self.use_project(None).await,
+            },
             Commands::Switch {
                 ref target,
                 install,
             } => self.quick_switch(target, install).await,
+            Commands::Reshim => self.reshim(),
+            Commands::ShimExec {
+                ref tool,
+                ref name,
+                ref args,
+            } => self.shim_exec(tool, name, args),
         }
     }
 
@@ -456,6 +769,98 @@ impl Cli {
             tool_id,
             versions.last().map(|v| v.raw.as_str()).unwrap_or("VERSION")
         );
+        if tool_id == "java" {
+            println!(
+                "  jcvm install java corretto-21    # Vendors: {}",
+                plugins::java::vendors::VENDOR_IDS.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn list_remote_releases(&self, tool_id: &str, major: u32) -> Result<()> {
+        let metadata = self.get_tool_metadata(tool_id)?;
+
+        print_info(&format!(
+            "Fetching {} {} releases...",
+            metadata.display_name(),
+            major
+        ));
+
+        let versions = self
+            .tool_manager
+            .list_remote_releases(tool_id, major)
+            .await?;
+
+        if versions.is_empty() {
+            print_warning(&format!(
+                "No {} {} releases found",
+                metadata.display_name(),
+                major
+            ));
+            return Ok(());
+        }
+
+        let installed: Vec<ToolVersion> = self
+            .tool_manager
+            .list_installed(Some(tool_id))?
+            .into_iter()
+            .map(|i| i.version)
+            .collect();
+
+        println!(
+            "\n{}",
+            format!("Available {} {} Releases:", metadata.display_name(), major)
+                .green()
+                .bold()
+        );
+
+        for version in &versions {
+            let date = version
+                .release_date
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+
+            let mut markers = Vec::new();
+            if version.is_lts && self.config.show_lts_indicator {
+                markers.push("LTS".green().to_string());
+            }
+            if installed.iter().any(|i| i.raw == version.raw) {
+                markers.push("installed".blue().to_string());
+            }
+            let marker_str = if markers.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", markers.join(", "))
+            };
+
+            println!(
+                "  {} {}{}",
+                format!("{:<24}", version.raw).cyan(),
+                date.dimmed(),
+                marker_str
+            );
+        }
+
+        // Point out how far behind the newest installed release is
+        let latest = &versions[0];
+        let newest_installed = installed
+            .iter()
+            .filter(|i| i.major == major && i.vendor == latest.vendor)
+            .max();
+        if let Some(current) = newest_installed {
+            let behind = versions.iter().filter(|v| *v > current).count();
+            if behind > 0 {
+                print_warning(&format!(
+                    "Newest installed {} is {} release(s) behind {}",
+                    current.raw, behind, latest.raw
+                ));
+            }
+        }
+
+        println!("\n{}", "Usage:".yellow());
+        println!("  jcvm install --tool {} {}", tool_id, latest.raw);
 
         Ok(())
     }
@@ -484,16 +889,110 @@ impl Cli {
         println!("\n{}", "Next steps:".yellow());
         println!(
             "  jcvm use --tool {} {}    # Activate this version",
-            tool_id, version_str
+            tool_id, installed.version.raw
         );
         println!(
             "  jcvm alias --tool {} default {}    # Set as default",
-            tool_id, version_str
+            tool_id, installed.version.raw
         );
 
         Ok(())
     }
 
+    /// Install every tool version requested by the project in the current directory, or only
+    /// the one for `tool_filter`
+    async fn install_project(&self, force: bool, tool_filter: Option<&str>) -> Result<()> {
+        if tool_filter.is_none() {
+            let tools = self.registry.list_plugins()?;
+            for request in project::unsupported_requests(&std::env::current_dir()?, &tools)? {
+                print_warning(&format!(
+                    "Skipping {} {} from {}: no plugin for '{}'",
+                    request.tool_id,
+                    request.version,
+                    request.source_name(),
+                    request.tool_id
+                ));
+            }
+        }
+
+        let mut requests = self.project_requests()?;
+        if let Some(tool_id) = tool_filter {
+            self.get_tool_metadata(tool_id)?;
+            requests.retain(|r| r.tool_id == tool_id);
+            if requests.is_empty() {
+                print_warning(&format!(
+                    "The project in the current directory doesn't request a {} version",
+                    tool_id
+                ));
+                println!("\n{}", "Install a specific version:".yellow());
+                println!("  jcvm install --tool {} <version>", tool_id);
+                return Ok(());
+            }
+        }
+        if requests.is_empty() {
+            print_warning(&format!(
+                "No {} or version files in the current directory",
+                PROJECT_FILE
+            ));
+            println!(
+                "\n{}",
+                format!("Declare tools in {}:", PROJECT_FILE).yellow()
+            );
+            println!("  [tools]");
+            println!("  java = \"21\"");
+            return Ok(());
+        }
+
+        for request in requests {
+            if !force {
+                if let Some((_, installed)) = self.installed_candidate(&request)? {
+                    print_info(&format!(
+                        "{} {} already installed (from {})",
+                        request.tool_id,
+                        installed,
+                        request.source_name()
+                    ));
+                    continue;
+                }
+            }
+            self.install(&request.tool_id, &request.version, force)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Tool versions requested by the project in the current directory
+    fn project_requests(&self) -> Result<Vec<VersionRequest>> {
+        let tools = self.registry.list_plugins()?;
+        project::resolve_all(&std::env::current_dir()?, &tools)
+    }
+
+    /// Tool versions for this shell session: `jcvm shell` overrides, then the project's
+    fn session_requests(&self) -> Result<Vec<VersionRequest>> {
+        let mut requests = self.project_requests()?;
+        for tool_id in self.registry.list_plugins()? {
+            if let Some(session) = project::session_version(&tool_id) {
+                requests.retain(|r| r.tool_id != tool_id);
+                requests.push(session);
+            }
+        }
+        Ok(requests)
+    }
+
+    /// First candidate of a project request that is installed, with the version it resolves to
+    fn installed_candidate(&self, request: &VersionRequest) -> Result<Option<(String, String)>> {
+        for candidate in request.candidates() {
+            if let Some(installed) = self
+                .tool_manager
+                .resolve_installed_version(&request.tool_id, candidate)?
+            {
+                return Ok(Some((candidate.to_string(), installed)));
+            }
+        }
+        Ok(None)
+    }
+
     fn list(&self, tool_id: &str, show_all: bool) -> Result<()> {
         if show_all {
             // List all tools
@@ -570,6 +1069,10 @@ impl Cli {
                 markers.push("LTS".yellow().to_string());
             }
 
+            if inst.version.is_pre_release() {
+                markers.push("pre-release".red().to_string());
+            }
+
             let marker_str = if markers.is_empty() {
                 String::new()
             } else {
@@ -594,27 +1097,14 @@ impl Cli {
         print_success(&format!(
             "Now using {} {}",
             metadata.display_name(),
-            version
+            ctx.version.raw
         ));
         println!("  Path: {}", ctx.home_path.display().to_string().dimmed());
+        self.check_engines(&ctx)?;
 
         // Check if shell integration is installed
         let shell = Shell::detect();
-        let has_shell_integration = if let Some(shell) = shell {
-            if let Some(config_file) = shell.config_file() {
-                if config_file.exists() {
-                    std::fs::read_to_string(&config_file)
-                        .map(|contents| contents.contains("JCVM_DIR"))
-                        .unwrap_or(false)
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        } else {
-            false
-        };
+        let has_shell_integration = Shell::integration_installed(shell);
 
         if !has_shell_integration {
             println!(
@@ -622,7 +1112,7 @@ impl Cli {
                 "⚠️  Shell integration not installed".yellow().bold()
             );
             println!("{}", "To activate in your current shell, run:".yellow());
-            let activation = generate_activation_script(ctx.home_path.to_str().unwrap())?;
+            let activation = generate_activation_script(&ctx.env)?;
             println!("{}", activation.dimmed());
             println!(
                 "\n{}",
@@ -634,6 +1124,108 @@ impl Cli {
         Ok(())
     }
 
+    /// Warn when an activated Node version falls outside the project's `engines.node` range
+    fn check_engines(&self, ctx: &ActivationContext) -> Result<()> {
+        if ctx.tool_id != "node" {
+            return Ok(());
+        }
+        let Some(engines) = project::package_json::find_engines(&std::env::current_dir()?)? else {
+            return Ok(());
+        };
+
+        let satisfied = VersionConstraint::parse(&engines.version)
+            .map(|c| c.matches(&ctx.version))
+            .unwrap_or(true);
+        if !satisfied {
+            print_warning(&format!(
+                "Node.js {} does not satisfy engines.node \"{}\" (from {})",
+                ctx.version.raw,
+                engines.version,
+                engines.source_name()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Activate the versions requested by the project in the current directory
+    async fn use_project(&self, tool_filter: Option<&str>) -> Result<()> {
+        let dir = std::env::current_dir()?;
+        let project = Project::find(&dir)?;
+        let requests: Vec<VersionRequest> = self
+            .project_requests()?
+            .into_iter()
+            .filter(|r| tool_filter.is_none_or(|tool| r.tool_id == tool))
+            .collect();
+
+        if requests.is_empty() {
+            print_warning(&format!(
+                "No {} or version files in the current directory",
+                PROJECT_FILE
+            ));
+            println!("\n{}", "Use a specific version:".yellow());
+            println!("  jcvm use --tool <tool> <version>");
+            return Ok(());
+        }
+
+        let install_missing = project
+            .as_ref()
+            .is_some_and(|p| p.config.settings.install_on_enter);
+        // Shell hooks run `jcvm use` with output redirected; only prompt a user at a terminal
+        let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+        let mut env = Vec::new();
+
+        for request in requests {
+            let version = match self.installed_candidate(&request)? {
+                Some((candidate, _)) => candidate,
+                None if install_missing
+                    || (interactive
+                        && confirm(&format!(
+                            "{} {} is not installed (from {}). Install it now?",
+                            request.tool_id,
+                            request.version,
+                            request.source_name()
+                        ))) =>
+                {
+                    self.install(&request.tool_id, &request.version, false)
+                        .await?;
+                    request.version.clone()
+                }
+                None => {
+                    print_warning(&format!(
+                        "{} {} is not installed (from {})",
+                        request.tool_id,
+                        request.version,
+                        request.source_name()
+                    ));
+                    println!("  Run {} to install project tools", "jcvm install".cyan());
+                    continue;
+                }
+            };
+
+            let ctx = self
+                .tool_manager
+                .set_current(&request.tool_id, &version)
+                .await?;
+            print_success(&format!(
+                "Now using {} {} (from {})",
+                ctx.tool_info.name,
+                ctx.version.raw,
+                request.source_name()
+            ));
+            self.check_engines(&ctx)?;
+            env.extend(ctx.env);
+        }
+
+        if let Some(project) = &project {
+            env.extend(project.env());
+        }
+        if !env.is_empty() {
+            self.print_shell_hint(&env);
+        }
+
+        Ok(())
+    }
+
     fn current(&self, tool_id: &str, show_all: bool) -> Result<()> {
         if show_all {
             // Show current for all tools
@@ -688,34 +1280,76 @@ impl Cli {
         Ok(())
     }
 
-    async fn local(&self, tool_id: &str, version: Option<String>) -> Result<()> {
+    async fn local(
+        &self,
+        tool_id: &str,
+        version: Option<String>,
+        use_tool_versions: bool,
+    ) -> Result<()> {
         let metadata = self.get_tool_metadata(tool_id)?;
-        let version_file = format!(".{}-version", tool_id);
+        let dir = std::env::current_dir()?;
+        // `.tool-versions` only when asked for; projects with a jcvm.toml keep their versions
+        // there instead of per-tool dotfiles
+        let version_file = if use_tool_versions {
+            TOOL_VERSIONS_FILE.to_string()
+        } else if dir.join(PROJECT_FILE).is_file() {
+            PROJECT_FILE.to_string()
+        } else {
+            format!(".{}-version", tool_id)
+        };
+        let existed = dir.join(&version_file).is_file();
 
         if let Some(version_str) = version {
-            // Check if version is installed
-            let installs = self.tool_manager.list_installed(Some(tool_id))?;
-            let is_installed = installs.iter().any(|i| i.version.raw == version_str);
+            // Check if an installed version satisfies the version or constraint
+            let resolved = self
+                .tool_manager
+                .resolve_installed_version(tool_id, &version_str)?;
 
-            if !is_installed {
+            let Some(resolved) = resolved else {
                 print_error(&format!(
                     "{} {} is not installed",
                     metadata.display_name(),
                     version_str
                 ));
                 println!("\n{}", "Install it first:".yellow());
-                println!("  jcvm install --tool {} {}", tool_id, version_str);
+                println!("  jcvm install --tool {} \"{}\"", tool_id, version_str);
                 return Ok(());
-            }
+            };
 
-            // Write version file
-            std::fs::write(&version_file, format!("{}\n", version_str))?;
+            // Write version file (constraints are kept as written)
+            match version_file.as_str() {
+                PROJECT_FILE => Project::write_tool_version(&dir, tool_id, &version_str)?,
+                TOOL_VERSIONS_FILE => tool_versions::write_tool_version(
+                    &dir,
+                    tool_id,
+                    &tool_versions::asdf_version(tool_id, &version_str, &resolved),
+                )?,
+                _ => std::fs::write(&version_file, format!("{}\n", version_str))?,
+            }
             print_success(&format!(
                 "Set local {} version to {}",
                 metadata.display_name(),
                 version_str
             ));
-            println!("  Created {} file", version_file);
+            if resolved != version_str {
+                println!("  Resolves to {}", resolved.cyan());
+            }
+            if existed {
+                println!("  Updated {}", version_file);
+            } else {
+                println!("  Created {} file", version_file);
+            }
+            let shadowed = version_file != TOOL_VERSIONS_FILE
+                && version_file != PROJECT_FILE
+                && tool_versions::load(&dir)?
+                    .iter()
+                    .any(|r| r.tool_id == tool_id);
+            if shadowed {
+                print_warning(&format!(
+                    "{} also sets {} and takes precedence; use --tool-versions to update it",
+                    TOOL_VERSIONS_FILE, tool_id
+                ));
+            }
 
             // Activate it for current shell
             if (self.tool_manager.set_current(tool_id, &version_str).await).is_ok() {
@@ -723,14 +1357,14 @@ impl Cli {
             }
         } else {
             // Show current local version
-            if let Ok(content) = std::fs::read_to_string(&version_file) {
-                let version = content.trim();
+            if let Some(request) = project::resolve_version(&dir, tool_id)? {
                 println!(
-                    "{}: {}",
+                    "{}: {} (from {})",
                     format!("Local {} version", metadata.display_name())
                         .green()
                         .bold(),
-                    version.cyan()
+                    request.version.cyan(),
+                    request.source_name()
                 );
             } else {
                 print_warning(&format!("No {} file in current directory", version_file));
@@ -977,34 +1611,19 @@ impl Cli {
 
         if !ctx.env.is_empty() {
             println!("  Env:");
-            for (key, value) in &ctx.env {
-                println!("    {}={}", key.green(), value.dimmed());
+            for action in &ctx.env {
+                println!("    {}", action.to_string().dimmed());
             }
         }
 
         if tool_id == "java" {
-            let home_str = ctx.home_path.display().to_string();
-            self.print_shell_hint(&home_str);
+            self.print_shell_hint(&ctx.env);
         }
     }
 
-    fn print_shell_hint(&self, java_home: &str) {
+    fn print_shell_hint(&self, env: &[EnvAction]) {
         let shell = Shell::detect();
-        let has_integration = if let Some(shell) = shell {
-            if let Some(config_file) = shell.config_file() {
-                if config_file.exists() {
-                    std::fs::read_to_string(&config_file)
-                        .map(|contents| contents.contains("JCVM_DIR"))
-                        .unwrap_or(false)
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        } else {
-            false
-        };
+        let has_integration = Shell::integration_installed(shell);
 
         if !has_integration {
             println!(
@@ -1012,7 +1631,7 @@ impl Cli {
                 "⚠️  Shell integration not installed".yellow().bold()
             );
             println!("{}", "To activate in your current shell, run:".yellow());
-            if let Ok(activation) = generate_activation_script(java_home) {
+            if let Ok(activation) = generate_activation_script(env) {
                 println!("{}", activation.dimmed());
             }
             println!(
@@ -1032,7 +1651,8 @@ impl Cli {
                 .push(install);
         }
 
-        for (tool_id, mut entries) in grouped {
+        // Entries arrive from `list_installed` newest first
+        for (tool_id, entries) in grouped {
             let title = self
                 .tool_manager
                 .metadata(&tool_id)
@@ -1040,8 +1660,6 @@ impl Cli {
                 .unwrap_or_else(|_| tool_id.clone());
             println!("\n{}", title.green().bold());
 
-            entries.sort_by(|a, b| a.version.raw.cmp(&b.version.raw));
-
             for entry in entries {
                 let mut markers = Vec::new();
                 if entry.is_current {
@@ -1053,6 +1671,9 @@ impl Cli {
                 if entry.version.is_lts {
                     markers.push("LTS".yellow().to_string());
                 }
+                if entry.version.is_pre_release() {
+                    markers.push("pre-release".red().to_string());
+                }
 
                 let mut line = format!("  {}", entry.version.raw.cyan());
                 if !markers.is_empty() {
@@ -1067,7 +1688,76 @@ impl Cli {
         }
     }
 
-    fn parse_tool_target(target: &str) -> Result<(String, String)> {
+    /// Split `install` positionals into tool and version (`[TOOL] VERSION`)
+    fn install_target(tool: Option<&str>, args: &[String]) -> (String, String) {
+        let tool = tool.unwrap_or("java");
+        match args {
+            [tool, version] => (tool.to_lowercase(), version.clone()),
+            [version] => (tool.to_lowercase(), version.clone()),
+            _ => (tool.to_lowercase(), String::new()),
+        }
+    }
+
+    /// Append a Java image type to a version request ("21" + jre -> "21-jre")
+    fn with_image(tool: &str, version: &str, image: Option<&str>) -> Result<String> {
+        let Some(image) = image else {
+            return Ok(version.to_string());
+        };
+
+        let image = image.to_lowercase();
+        if tool != "java" {
+            return Err(JcvmError::InvalidVersion(format!(
+                "--image is only supported for java, not {}",
+                tool
+            )));
+        }
+        if !crate::plugins::java::vendors::IMAGE_TYPES.contains(&image.as_str()) {
+            return Err(JcvmError::InvalidVersion(format!(
+                "unknown image type '{}' (expected one of: {})",
+                image,
+                crate::plugins::java::vendors::IMAGE_TYPES.join(", ")
+            )));
+        }
+        if image == crate::plugins::java::vendors::DEFAULT_IMAGE {
+            return Ok(version.to_string());
+        }
+        Ok(format!("{}-{}", version, image))
+    }
+
+    /// Split `jcvm exec` arguments into `<tool>@<version>` targets and the command. Before a
+    /// `--` every argument must be a target; without one, leading arguments naming a known
+    /// tool are targets and the rest is the command.
+    fn split_exec_args(
+        args: &[String],
+        tools: &[String],
+    ) -> Result<(Vec<ToolTarget>, Vec<String>)> {
+        if let Some(separator) = args.iter().position(|arg| arg == "--") {
+            let targets = args[..separator]
+                .iter()
+                .map(|target| match target.split_once('@') {
+                    Some(_) => Self::parse_tool_target(target),
+                    None => Err(JcvmError::InvalidVersion(format!(
+                        "{} (expected <tool>@<version> before --)",
+                        target
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok((targets, args[separator + 1..].to_vec()));
+        }
+
+        let mut targets = Vec::new();
+        for (idx, arg) in args.iter().enumerate() {
+            match arg.split_once('@') {
+                Some((tool, _)) if tools.iter().any(|t| t.eq_ignore_ascii_case(tool)) => {
+                    targets.push(Self::parse_tool_target(arg)?);
+                }
+                _ => return Ok((targets, args[idx..].to_vec())),
+            }
+        }
+        Ok((targets, Vec::new()))
+    }
+
+    fn parse_tool_target(target: &str) -> Result<ToolTarget> {
         let trimmed = target.trim();
         if trimmed.is_empty() {
             return Err(JcvmError::InvalidVersion("missing target".to_string()));
@@ -1202,7 +1892,23 @@ impl Cli {
         Ok(())
     }
 
-    fn shell_init(&self, shell_type: Option<String>) -> Result<()> {
+    fn shell_init(&self, shell_type: Option<&str>, update: bool) -> Result<()> {
+        let shell = Shell::select(shell_type)?;
+
+        if update {
+            let activation = self.tool_manager.current_environment()?;
+            let (config_file, previous) = shell.update_hook(&self.config, &activation)?;
+            print_success(&format!(
+                "Shell integration updated from {} to v{}",
+                previous.map_or_else(|| "an unknown version".to_string(), |v| format!("v{}", v)),
+                env!("CARGO_PKG_VERSION")
+            ));
+            println!("  Updated: {}", config_file.display());
+            println!("\n{}", "Reload your shell:".yellow());
+            println!("  {}", shell.reload_hint());
+            return Ok(());
+        }
+
         // Check if this is the first run (no versions directory or empty)
         let is_first_run = !self.config.versions_dir.exists()
             || std::fs::read_dir(&self.config.versions_dir)
@@ -1280,38 +1986,15 @@ impl Cli {
             println!();
         }
 
-        let shell = if let Some(shell_str) = shell_type {
-            match shell_str.to_lowercase().as_str() {
-                "bash" => Shell::Bash,
-                "zsh" => Shell::Zsh,
-                "fish" => Shell::Fish,
-                "powershell" | "pwsh" => Shell::PowerShell,
-                _ => {
-                    print_error(&format!("Unsupported shell: {}", shell_str));
-                    return Ok(());
-                }
-            }
-        } else {
-            Shell::detect().unwrap_or(Shell::Bash)
-        };
-
         println!("{}", "Installing shell integration...".yellow());
 
-        match shell.install_hook(&self.config) {
-            Ok(_) => {
+        let activation = self.tool_manager.current_environment()?;
+        match shell.install_hook(&self.config, &activation) {
+            Ok(config_file) => {
                 print_success("Shell integration installed");
-
-                if let Some(config_file) = shell.config_file() {
-                    println!("  Updated: {}", config_file.display());
-                    println!("\n{}", "Reload your shell:".yellow());
-
-                    match shell {
-                        Shell::Bash => println!("  source ~/.bashrc"),
-                        Shell::Zsh => println!("  source ~/.zshrc"),
-                        Shell::Fish => println!("  source ~/.config/fish/config.fish"),
-                        Shell::PowerShell => println!("  . $PROFILE"),
-                    }
-                }
+                println!("  Updated: {}", config_file.display());
+                println!("\n{}", "Reload your shell:".yellow());
+                println!("  {}", shell.reload_hint());
             }
             Err(e) => {
                 print_error(&format!("Failed to install shell integration: {}", e));
@@ -1321,14 +2004,54 @@ impl Cli {
         Ok(())
     }
 
+    fn shell_uninstall(&self, shell_type: Option<&str>) -> Result<()> {
+        let shell = Shell::select(shell_type)?;
+        let config_file = shell.uninstall_hook()?;
+        print_success("Shell integration removed");
+        println!("  Updated: {}", config_file.display());
+        println!("  Open a new shell to drop JCVM from your environment");
+        Ok(())
+    }
+
+    /// Print the init script to stdout only, so it can be eval'd
+    fn print_shell_init(&self, shell_type: &str) -> Result<()> {
+        let shell = Shell::select(Some(shell_type))?;
+        let activation = self.tool_manager.current_environment()?;
+        print!(
+            "{}",
+            shell.init_block(self.config.jcvm_dir.to_str().unwrap(), &activation)
+        );
+        Ok(())
+    }
+
     fn which(&self) -> Result<()> {
-        // Check local version first
-        if let Some(local_version) = VersionManager::read_local_version()? {
-            println!(
-                "{} {} (from .java-version)",
-                "Would use:".green().bold(),
-                local_version.to_string().cyan()
-            );
+        // Check session and project versions first (may be constraints such as "21.x")
+        let requests = self.session_requests()?;
+        for request in &requests {
+            let source = request.source_name();
+            match self.installed_candidate(request)? {
+                Some((candidate, resolved)) if resolved != candidate => println!(
+                    "{} {} {} (from {}: {})",
+                    "Would use:".green().bold(),
+                    request.tool_id,
+                    resolved.cyan(),
+                    source,
+                    candidate
+                ),
+                Some((_, resolved)) => println!(
+                    "{} {} {} (from {})",
+                    "Would use:".green().bold(),
+                    request.tool_id,
+                    resolved.cyan(),
+                    source
+                ),
+                None => print_warning(&format!(
+                    "No installed {} version satisfies {} (from {})",
+                    request.tool_id, request.version, source
+                )),
+            }
+        }
+        if requests.iter().any(|r| r.tool_id == "java") {
             return Ok(());
         }
 
@@ -1448,32 +2171,397 @@ impl Cli {
         Ok(())
     }
 
-    async fn exec(&self, version: &str, command: Vec<String>) -> Result<()> {
+    async fn exec(&self, version: Option<&str>, args: Vec<String>) -> Result<()> {
+        let tools = self.registry.list_plugins()?;
+        let (mut targets, command) = Self::split_exec_args(&args, &tools)?;
         if command.is_empty() {
-            print_error("No command specified");
-            return Ok(());
+            return Err(JcvmError::ConfigError(
+                "No command specified, e.g. `jcvm exec java@17 node@20 -- ./build.sh`".to_string(),
+            ));
+        }
+        if let Some(version) = version {
+            targets.retain(|(tool_id, _)| tool_id != "java");
+            targets.push(("java".to_string(), version.to_string()));
         }
 
-        let version_dir = self.config.get_version_dir(version);
-        if !version_dir.exists() {
-            print_error(&format!("JDK {} is not installed", version));
-            return Ok(());
+        if targets.is_empty() {
+            for request in self.session_requests()? {
+                let version = match self.installed_candidate(&request)? {
+                    Some((candidate, _)) => candidate,
+                    None => {
+                        return Err(JcvmError::VersionNotFound(format!(
+                            "{}@{} (requested by {})",
+                            request.tool_id,
+                            request.version,
+                            request.source_name()
+                        )))
+                    }
+                };
+                targets.push((request.tool_id, version));
+            }
+        }
+        if targets.is_empty() {
+            return Err(JcvmError::ConfigError(format!(
+                "No tool versions given and no {} or version files found",
+                PROJECT_FILE
+            )));
         }
 
-        let java_home = version_dir.to_str().unwrap();
-        let bin_dir = version_dir.join("bin");
+        let mut env = Vec::new();
+        for (tool_id, version) in &targets {
+            self.get_tool_metadata(tool_id)?;
+            env.extend(self.tool_manager.environment(tool_id, version)?);
+        }
+        if let Some(project) = Project::find(&std::env::current_dir()?)? {
+            env.extend(project.env());
+        }
 
         let mut cmd = std::process::Command::new(&command[0]);
         cmd.args(&command[1..]);
-        cmd.env("JAVA_HOME", java_home);
+        crate::core::env::apply_to_command(&env, &mut cmd);
+        Self::run_in_place(cmd)
+    }
+
+    fn run_tasks(&self, names: &[String], sequential: bool) -> Result<()> {
+        let project = Project::find(&std::env::current_dir()?)?.ok_or_else(|| {
+            JcvmError::ConfigError(format!("No {} found for `jcvm run`", PROJECT_FILE))
+        })?;
+        let tasks = &project.config.tasks;
+
+        if names.is_empty() {
+            if tasks.is_empty() {
+                print_warning(&format!("No [tasks] in {}", project.path().display()));
+                return Ok(());
+            }
+            println!("{}", "Tasks:".green().bold());
+            for (name, task) in tasks {
+                let summary = task
+                    .description
+                    .clone()
+                    .or_else(|| task.run.clone())
+                    .unwrap_or_else(|| format!("runs {}", task.depends.join(", ")));
+                println!("  {:<16} {}", name.cyan(), summary);
+            }
+            return Ok(());
+        }
+
+        // Versions the session and project select; tasks may override them per tool
+        let mut base = BTreeMap::new();
+        for request in self.session_requests()? {
+            let Some((candidate, _)) = self.installed_candidate(&request)? else {
+                return Err(JcvmError::VersionNotFound(format!(
+                    "{}@{} (requested by {})",
+                    request.tool_id,
+                    request.version,
+                    request.source_name()
+                )));
+            };
+            base.insert(request.tool_id, candidate);
+        }
+
+        for stage in project::tasks::plan(tasks, names)? {
+            let mut commands = Vec::new();
+            for name in stage {
+                let task = &tasks[&name];
+                let Some(run) = &task.run else { continue };
+
+                let mut versions = base.clone();
+                versions.extend(task.tools.clone());
+                let mut env = Vec::new();
+                for (tool_id, version) in &versions {
+                    env.extend(self.tool_manager.environment(tool_id, version)?);
+                }
+                env.extend(project.env());
+                env.extend(task.env(&project.root));
+
+                let mut cmd = if cfg!(windows) {
+                    let mut cmd = std::process::Command::new("cmd");
+                    cmd.arg("/C").arg(run);
+                    cmd
+                } else {
+                    let mut cmd = std::process::Command::new("sh");
+                    cmd.arg("-c").arg(run);
+                    cmd
+                };
+                cmd.current_dir(project.root.join(task.dir.as_deref().unwrap_or(".")));
+                crate::core::env::apply_to_command(&env, &mut cmd);
+                commands.push((name, run, cmd));
+            }
+
+            // Tasks of one stage don't depend on each other
+            let mut running = Self::spawn_tasks(commands, sequential)?;
+            Self::wait_for_tasks(&mut running)?;
+        }
+
+        Ok(())
+    }
+
+    /// Start a stage's tasks, waiting for each one when `sequential`. When a task can't be
+    /// started, the ones already running are stopped before the error is returned.
+    fn spawn_tasks(
+        commands: Vec<(String, &String, std::process::Command)>,
+        sequential: bool,
+    ) -> Result<Vec<(String, std::process::Child)>> {
+        let mut running = Vec::new();
+        for (name, run, mut cmd) in commands {
+            println!("{} {}", format!("[{}]", name).cyan().bold(), run);
+            match cmd.spawn() {
+                Ok(child) => running.push((name, child)),
+                Err(e) => {
+                    for (_, mut child) in running {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                    }
+                    return Err(JcvmError::ConfigError(format!(
+                        "Could not start task '{}': {}",
+                        name, e
+                    )));
+                }
+            }
+            if sequential {
+                Self::wait_for_tasks(&mut running)?;
+            }
+        }
+        Ok(running)
+    }
+
+    /// Wait for running tasks; when one failed, exit with its code once all have finished
+    fn wait_for_tasks(running: &mut Vec<(String, std::process::Child)>) -> Result<()> {
+        let mut failed = None;
+        for (name, mut child) in running.drain(..) {
+            let status = child.wait()?;
+            if !status.success() && failed.is_none() {
+                failed = Some((name, status));
+            }
+        }
+        if let Some((name, status)) = failed {
+            print_error(&format!("Task '{}' failed ({})", name, status));
+            std::process::exit(status.code().unwrap_or(1));
+        }
+        Ok(())
+    }
+
+    fn hook_env(&self, shell: Option<&str>) -> Result<()> {
+        let shell = Shell::select(shell)?;
+        let changes = self.session_changes(self.session_requests()?)?;
+
+        let output = shell.render_hook_env(&changes);
+        if !output.is_empty() {
+            println!("{}", output);
+        }
+        Ok(())
+    }
+
+    /// Set or clear `jcvm shell` overrides. Prints commands for the shell integration to
+    /// evaluate, since a child process can't change its shell's environment.
+    fn session_shell(
+        &self,
+        tool_id: Option<&str>,
+        version: Option<&str>,
+        shell: Option<&str>,
+    ) -> Result<()> {
+        let shell = Shell::select(shell)?;
+        let tools = match tool_id {
+            Some(tool_id) => {
+                self.get_tool_metadata(tool_id)?;
+                vec![tool_id.to_string()]
+            }
+            None => self.registry.list_plugins()?,
+        };
+
+        let mut requests = self.session_requests()?;
+        let mut changes = Vec::new();
+        match (tool_id, version) {
+            (Some(tool_id), Some(version)) => {
+                let var = project::session_env_var(tool_id);
+                let request = VersionRequest::new(tool_id, version, Path::new(&var));
+                let Some((_, resolved)) = self.installed_candidate(&request)? else {
+                    eprintln!(
+                        "jcvm: install it with `jcvm install --tool {} {}`",
+                        tool_id, version
+                    );
+                    return Err(JcvmError::VersionNotFound(format!(
+                        "{}@{}",
+                        tool_id, version
+                    )));
+                };
+                requests.retain(|r| r.tool_id != tool_id);
+                requests.push(request);
+                changes.push(EnvAction::set(var, version));
+                eprintln!("jcvm: using {} {} in this shell", tool_id, resolved);
+            }
+            _ => {
+                let project = self.project_requests()?;
+                for tool_id in &tools {
+                    let var = project::session_env_var(tool_id);
+                    if std::env::var_os(&var).is_none() {
+                        continue;
+                    }
+                    requests.retain(|r| &r.tool_id != tool_id);
+                    requests.extend(project.iter().filter(|r| &r.tool_id == tool_id).cloned());
+                    changes.push(EnvAction::unset(var));
+                    eprintln!("jcvm: cleared the {} override of this shell", tool_id);
+                }
+                if changes.is_empty() {
+                    eprintln!("jcvm: no `jcvm shell` override to clear");
+                }
+            }
+        }
 
-        // Add JDK bin to PATH
-        let path = std::env::var("PATH").unwrap_or_default();
-        let new_path = format!("{}:{}", bin_dir.display(), path);
-        cmd.env("PATH", new_path);
+        changes.extend(self.session_changes(requests)?);
+        if std::io::stdout().is_terminal() {
+            eprintln!(
+                "jcvm: shell integration is not active; run `jcvm shell-init` or evaluate this output"
+            );
+        }
+        println!("{}", shell.render_hook_env(&changes));
+        Ok(())
+    }
+
+    /// Changes applying `requests` and the project's `[env]` to this shell session, undoing
+    /// what the previous `hook-env` run applied and no longer holds
+    fn session_changes(&self, requests: Vec<VersionRequest>) -> Result<Vec<EnvAction>> {
+        let project = Project::find(&std::env::current_dir()?)?;
+        let install_missing = project
+            .as_ref()
+            .is_some_and(|p| p.config.settings.install_on_enter);
+
+        let mut actions = Vec::new();
+        for request in requests {
+            let mut installed = self.installed_candidate(&request)?;
+            if installed.is_none() && install_missing {
+                // stdout is eval'd by the shell; the install's output goes to stderr
+                eprintln!(
+                    "jcvm: installing {} {} (from {})",
+                    request.tool_id,
+                    request.version,
+                    request.source_name()
+                );
+                let status =
+                    Self::install_to_stderr(&std::env::current_exe()?, &request).status()?;
+                if status.success() {
+                    installed = self.installed_candidate(&request)?;
+                }
+            }
+
+            match installed {
+                Some((candidate, _)) => actions.extend(
+                    self.tool_manager
+                        .environment(&request.tool_id, &candidate)?,
+                ),
+                None => eprintln!(
+                    "jcvm: {} {} is not installed (from {}); run `jcvm install`",
+                    request.tool_id,
+                    request.version,
+                    request.source_name()
+                ),
+            }
+        }
+        if let Some(project) = &project {
+            actions.extend(project.env());
+        }
+
+        let state = HookState::parse(std::env::var(HOOK_STATE_ENV).ok().as_deref());
+        let (mut changes, next) = hook_env::diff(&state, &actions, |key| std::env::var(key).ok());
+        if next.is_empty() {
+            if !state.is_empty() {
+                changes.push(EnvAction::unset(HOOK_STATE_ENV));
+            }
+        } else if next != state {
+            changes.push(EnvAction::set(HOOK_STATE_ENV, next.to_json()));
+        }
+        Ok(changes)
+    }
 
-        let status = cmd.status()?;
-        std::process::exit(status.code().unwrap_or(1));
+    /// `jcvm install` for a request, as a child process writing all its output to stderr
+    fn install_to_stderr(jcvm: &Path, request: &VersionRequest) -> std::process::Command {
+        let mut cmd = std::process::Command::new(jcvm);
+        cmd.args(["install", "--tool", &request.tool_id, &request.version])
+            .stdin(std::process::Stdio::null())
+            .stdout(std::io::stderr());
+        cmd
+    }
+
+    fn reshim(&self) -> Result<()> {
+        let shims = self.tool_manager.reshim()?;
+        let dir = self.config.shims_dir();
+        print_success(&format!(
+            "Updated {} shims in {}",
+            shims.len(),
+            dir.display()
+        ));
+
+        let on_path = std::env::var_os("PATH")
+            .is_some_and(|path| std::env::split_paths(&path).any(|p| p == dir));
+        if !on_path {
+            println!(
+                "\n{}",
+                "Add the shims directory to PATH to use project versions everywhere:".yellow()
+            );
+            println!("  export PATH=\"{}:$PATH\"", dir.display());
+        }
+        Ok(())
+    }
+
+    /// Version of a tool for the current directory: the project's request, then the current
+    /// version, then the default alias
+    fn shim_version(&self, tool_id: &str) -> Result<String> {
+        let request = match project::session_version(tool_id) {
+            Some(session) => Some(session),
+            None => project::resolve_version(&std::env::current_dir()?, tool_id)?,
+        };
+        if let Some(request) = request {
+            return match self.installed_candidate(&request)? {
+                Some((candidate, _)) => Ok(candidate),
+                None => Err(JcvmError::VersionNotFound(format!(
+                    "{}@{} (requested by {})",
+                    tool_id,
+                    request.version,
+                    request.source_name()
+                ))),
+            };
+        }
+
+        if let Some(current) = self.tool_manager.get_current(tool_id)? {
+            return Ok(current);
+        }
+        self.tool_manager
+            .get_alias(tool_id, "default")?
+            .ok_or_else(|| {
+                JcvmError::ConfigError(format!(
+                    "No {} version selected; run `jcvm use --tool {} <version>`",
+                    tool_id, tool_id
+                ))
+            })
+    }
+
+    fn shim_exec(&self, tool_id: &str, name: &str, args: &[String]) -> Result<()> {
+        let version = self.shim_version(tool_id)?;
+        let executable = self.tool_manager.executable(tool_id, &version, name)?;
+
+        let mut env = self.tool_manager.environment(tool_id, &version)?;
+        if let Some(project) = Project::find(&std::env::current_dir()?)? {
+            env.extend(project.env());
+        }
+
+        let mut cmd = std::process::Command::new(&executable);
+        cmd.args(args);
+        crate::core::env::apply_to_command(&env, &mut cmd);
+        Self::run_in_place(cmd)
+    }
+
+    /// Run a command in place of jcvm, so the caller sees its signals and exit code unchanged
+    fn run_in_place(mut cmd: std::process::Command) -> Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            Err(cmd.exec().into())
+        }
+        #[cfg(not(unix))]
+        {
+            let status = cmd.status()?;
+            std::process::exit(status.code().unwrap_or(1));
+        }
     }
 
     async fn detect(&self, tool_filter: Option<String>, auto_import: bool) -> Result<()> {
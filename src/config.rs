@@ -166,6 +166,16 @@ impl Config {
         }
     }
 
+    /// Directory holding the shim executables (`java`, `node`, `python3`, ...)
+    pub fn shims_dir(&self) -> PathBuf {
+        self.jcvm_dir.join("shims")
+    }
+
+    /// Directory holding declarative plugin definitions (`<tool>.toml`)
+    pub fn plugins_dir(&self) -> PathBuf {
+        self.jcvm_dir.join("plugins")
+    }
+
     pub fn tool_versions_dir(&self, tool_id: &str) -> PathBuf {
         self.tool_versions_dir_result(tool_id)
             .unwrap_or_else(|_| self.versions_dir.join(tool_id))
@@ -182,6 +192,7 @@ impl Config {
     }
 
     pub fn tool_version_dir(&self, tool_id: &str, version: &str) -> Result<PathBuf> {
+        check_version_name(version)?;
         Ok(self.tool_versions_dir_result(tool_id)?.join(version))
     }
 
@@ -216,10 +227,43 @@ impl Config {
     }
 }
 
+/// Reject versions that can't name a directory directly under `versions/<tool>/`
+pub fn check_version_name(version: &str) -> Result<()> {
+    if version.is_empty()
+        || version.starts_with('.')
+        || version.contains(['/', '\\'])
+        || version.contains("..")
+    {
+        return Err(JcvmError::InvalidVersion(version.to_string()));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn version_dirs_stay_inside_the_tool_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let config = Config {
+            versions_dir: temp.path().join("versions"),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            config.tool_version_dir("node", "20.1.0").unwrap(),
+            temp.path().join("versions/node/20.1.0")
+        );
+        for version in ["../../x", "a/b", "a\\b", ".hidden", "1..2", ""] {
+            assert!(
+                config.tool_version_dir("node", version).is_err(),
+                "{}",
+                version
+            );
+        }
+    }
+
     #[test]
     fn test_config_default() {
         let config = Config::default();
@@ -0,0 +1,684 @@
+use crate::core::traits::ToolVersion;
+use crate::error::{JcvmError, Result};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Suffixes treated as pre-release channels rather than package variants
+const PRE_RELEASE_TAGS: &[&str] = &[
+    "ea", "alpha", "beta", "rc", "pre", "preview", "dev", "nightly",
+];
+
+/// Comparison operator of a single constraint term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// A single comparison against a fully expanded `major.minor.patch` triple
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: (u32, u32, u32),
+}
+
+impl Comparator {
+    fn new(op: Op, version: (u32, u32, u32)) -> Self {
+        Self { op, version }
+    }
+
+    fn matches(&self, candidate: (u32, u32, u32)) -> bool {
+        match self.op {
+            Op::Eq => candidate == self.version,
+            Op::Gt => candidate > self.version,
+            Op::Ge => candidate >= self.version,
+            Op::Lt => candidate < self.version,
+            Op::Le => candidate <= self.version,
+        }
+    }
+}
+
+/// A partially specified version such as `21`, `3.12` or `20.10.1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Partial {
+    major: u32,
+    minor: Option<u32>,
+    patch: Option<u32>,
+}
+
+impl Partial {
+    /// Parse a partial version. `x`, `X` and `*` components act as wildcards.
+    fn parse(input: &str) -> Option<Self> {
+        let trimmed = input.trim().trim_start_matches(['v', 'V']);
+        // Build metadata never affects range matching
+        let core = trimmed.split('+').next().unwrap_or(trimmed);
+
+        let mut components = Vec::new();
+        for part in core.split('.') {
+            if matches!(part, "x" | "X" | "*") {
+                break;
+            }
+            components.push(part.parse::<u32>().ok()?);
+        }
+
+        // Components past the patch (e.g. Corretto's "21.0.4.7.1") don't take part in matching
+        let major = *components.first()?;
+
+        Some(Self {
+            major,
+            minor: components.get(1).copied(),
+            patch: components.get(2).copied(),
+        })
+    }
+
+    fn is_full(&self) -> bool {
+        self.patch.is_some()
+    }
+
+    /// Lowest version covered by this partial (missing components are zero)
+    fn floor(&self) -> (u32, u32, u32) {
+        (self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0))
+    }
+
+    /// First version past the range covered by this partial
+    fn ceiling(&self) -> (u32, u32, u32) {
+        match (self.minor, self.patch) {
+            (None, _) => (self.major + 1, 0, 0),
+            (Some(minor), None) => (self.major, minor + 1, 0),
+            (Some(minor), Some(patch)) => (self.major, minor, patch + 1),
+        }
+    }
+
+    /// Comparators covering every version that starts with this partial
+    fn prefix_range(&self) -> Vec<Comparator> {
+        if self.is_full() {
+            vec![Comparator::new(Op::Eq, self.floor())]
+        } else {
+            vec![
+                Comparator::new(Op::Ge, self.floor()),
+                Comparator::new(Op::Lt, self.ceiling()),
+            ]
+        }
+    }
+
+    /// Upper bound for caret ranges: changes to the left-most non-zero component are excluded
+    fn caret_ceiling(&self) -> (u32, u32, u32) {
+        match (self.minor, self.patch) {
+            _ if self.major > 0 => (self.major + 1, 0, 0),
+            (None, _) => (self.major + 1, 0, 0),
+            (Some(minor), _) if minor > 0 => (0, minor + 1, 0),
+            (Some(minor), None) => (0, minor + 1, 0),
+            (Some(minor), Some(patch)) => (0, minor, patch + 1),
+        }
+    }
+
+    /// Upper bound for tilde ranges: patch-level changes when a minor is given
+    fn tilde_ceiling(&self) -> (u32, u32, u32) {
+        match self.minor {
+            Some(minor) => (self.major, minor + 1, 0),
+            None => (self.major + 1, 0, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConstraintKind {
+    /// Any version (`latest`, `*`, `x`)
+    Any,
+    /// Any version flagged as LTS by its plugin (`lts`, `lts/*`)
+    Lts,
+    /// A named LTS line such as `lts/iron` (matched against `lts:<name>` metadata)
+    LtsNamed(String),
+    /// Alternatives (`||`) of comparator sets that must all hold
+    Ranges(Vec<Vec<Comparator>>),
+}
+
+/// A version constraint expression such as `>=17 <22`, `^20.10`, `~3.12`, `21.x` or `lts`.
+///
+/// Constraints are matched against the numeric components of a [`ToolVersion`], so
+/// `3.1` matches `3.1.4` but not `3.13.7`. Supported syntax:
+///
+/// - Bare and partial versions: `21`, `3.12`, `20.10.1` (prefix match on components)
+/// - Wildcards: `21.x`, `3.12.*`
+/// - Comparators: `>=17`, `<22`, `>3.9`, `<=20.10`, `=21.0.4`
+/// - Caret and tilde ranges: `^20.10`, `~3.12`
+/// - Hyphen ranges: `17 - 21`
+/// - Intersections separated by whitespace or commas: `>=17 <22`, `>=3.10,<3.13`
+/// - Unions: `^18 || ^20`
+/// - Keywords: `lts`, `lts/*`, `lts/<codename>`, `latest`, `*`
+/// - Vendor prefixes: `corretto-17`, `temurin->=17 <22` (only versions from that vendor match)
+/// - Variant suffixes: `21-jre`, `corretto-17-jre` (only versions of that package variant match;
+///   without a suffix only the default variant matches)
+/// - Pre-release suffixes: `24-ea`, `22-rc` (only pre-releases with that tag match; without a
+///   suffix pre-releases never match, so `latest` and `21` only pick releases)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConstraint {
+    raw: String,
+    vendor: Option<String>,
+    variant: Option<String>,
+    pre_release: Option<String>,
+    kind: ConstraintKind,
+    plain: Option<Partial>,
+}
+
+impl VersionConstraint {
+    /// Parse a constraint expression
+    pub fn parse(input: &str) -> Result<Self> {
+        let raw = input.trim().to_string();
+        let invalid = || JcvmError::InvalidVersion(raw.clone());
+
+        if raw.is_empty() {
+            return Err(invalid());
+        }
+
+        let (vendor, expr) = Self::split_vendor(&raw);
+        let (expr, variant) = Self::split_variant(expr);
+        let (expr, pre_release) = Self::split_pre_release(expr);
+        if expr.is_empty() {
+            return Err(invalid());
+        }
+
+        let keyword = expr.to_lowercase();
+        let kind = match keyword.as_str() {
+            "latest" | "current" | "*" | "x" => Some(ConstraintKind::Any),
+            "lts" | "lts/*" => Some(ConstraintKind::Lts),
+            other => other
+                .strip_prefix("lts/")
+                .filter(|name| !name.is_empty())
+                .map(|name| ConstraintKind::LtsNamed(name.to_string())),
+        };
+        if let Some(kind) = kind {
+            return Ok(Self {
+                raw,
+                vendor,
+                variant,
+                pre_release,
+                kind,
+                plain: None,
+            });
+        }
+
+        let mut alternatives = Vec::new();
+        for alternative in expr.split("||") {
+            alternatives.push(Self::parse_set(alternative).ok_or_else(invalid)?);
+        }
+
+        let plain = if alternatives.len() == 1 {
+            Partial::parse(expr).filter(|_| expr.chars().next().is_some_and(|c| c != '='))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            raw,
+            vendor,
+            variant,
+            pre_release,
+            kind: ConstraintKind::Ranges(alternatives),
+            plain,
+        })
+    }
+
+    /// Split an optional `<vendor>-` prefix (e.g. `corretto-17`) from the expression
+    fn split_vendor(raw: &str) -> (Option<String>, &str) {
+        match raw.split_once('-') {
+            Some((prefix, rest))
+                if !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_alphabetic()) =>
+            {
+                (Some(prefix.to_lowercase()), rest.trim())
+            }
+            _ => (None, raw),
+        }
+    }
+
+    /// Split an optional `-<variant>` suffix (e.g. `21-jre`) from the expression
+    fn split_variant(expr: &str) -> (&str, Option<String>) {
+        match expr.rsplit_once('-') {
+            Some((rest, suffix))
+                if !rest.trim().is_empty()
+                    && !suffix.is_empty()
+                    && suffix.chars().all(|c| c.is_ascii_alphabetic())
+                    && !Self::is_pre_release_tag(suffix) =>
+            {
+                (rest.trim(), Some(suffix.to_lowercase()))
+            }
+            _ => (expr, None),
+        }
+    }
+
+    /// Split an optional `-<pre-release>` suffix (e.g. `24-ea`, `24-ea+15`) from the expression
+    fn split_pre_release(expr: &str) -> (&str, Option<String>) {
+        match expr.rsplit_once('-') {
+            Some((rest, suffix)) if !rest.trim().is_empty() => {
+                let tag = suffix.split('+').next().unwrap_or_default();
+                if Self::is_pre_release_tag(tag) {
+                    (rest.trim(), Some(tag.to_lowercase()))
+                } else {
+                    (expr, None)
+                }
+            }
+            _ => (expr, None),
+        }
+    }
+
+    /// Whether a suffix names a pre-release channel ("ea", "rc.1", "beta2") rather than a variant
+    fn is_pre_release_tag(tag: &str) -> bool {
+        let name: String = tag
+            .chars()
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect();
+        PRE_RELEASE_TAGS.contains(&name.to_lowercase().as_str())
+    }
+
+    /// Parse one intersection of comparator terms (no `||`)
+    fn parse_set(input: &str) -> Option<Vec<Comparator>> {
+        let normalized = input.replace(',', " ");
+        let mut tokens: Vec<String> = Vec::new();
+        let mut pending_op: Option<String> = None;
+
+        // Join operators that are separated from their version (e.g. ">= 17")
+        for token in normalized.split_whitespace() {
+            if matches!(token, ">" | ">=" | "<" | "<=" | "=" | "^" | "~") {
+                pending_op = Some(token.to_string());
+                continue;
+            }
+            match pending_op.take() {
+                Some(op) => tokens.push(format!("{}{}", op, token)),
+                None => tokens.push(token.to_string()),
+            }
+        }
+
+        if pending_op.is_some() || tokens.is_empty() {
+            return None;
+        }
+
+        // Hyphen range: "17 - 21"
+        if tokens.len() == 3 && tokens[1] == "-" {
+            let low = Partial::parse(&tokens[0])?;
+            let high = Partial::parse(&tokens[2])?;
+            let upper = if high.is_full() {
+                Comparator::new(Op::Le, high.floor())
+            } else {
+                Comparator::new(Op::Lt, high.ceiling())
+            };
+            return Some(vec![Comparator::new(Op::Ge, low.floor()), upper]);
+        }
+
+        let mut comparators = Vec::new();
+        for token in tokens {
+            comparators.extend(Self::parse_term(&token)?);
+        }
+        Some(comparators)
+    }
+
+    /// Desugar a single term such as `^20.10` into comparators
+    fn parse_term(token: &str) -> Option<Vec<Comparator>> {
+        let (op, rest) = if let Some(rest) = token.strip_prefix(">=") {
+            (">=", rest)
+        } else if let Some(rest) = token.strip_prefix("<=") {
+            ("<=", rest)
+        } else if let Some(rest) = token.strip_prefix('>') {
+            (">", rest)
+        } else if let Some(rest) = token.strip_prefix('<') {
+            ("<", rest)
+        } else if let Some(rest) = token.strip_prefix('=') {
+            ("=", rest)
+        } else if let Some(rest) = token.strip_prefix('^') {
+            ("^", rest)
+        } else if let Some(rest) = token.strip_prefix('~') {
+            ("~", rest)
+        } else {
+            ("", token)
+        };
+
+        if matches!(rest.trim(), "*" | "x" | "X") {
+            return Some(Vec::new());
+        }
+
+        let partial = Partial::parse(rest)?;
+
+        let comparators = match op {
+            "" | "=" => partial.prefix_range(),
+            ">=" => vec![Comparator::new(Op::Ge, partial.floor())],
+            "<" => vec![Comparator::new(Op::Lt, partial.floor())],
+            ">" if partial.is_full() => vec![Comparator::new(Op::Gt, partial.floor())],
+            ">" => vec![Comparator::new(Op::Ge, partial.ceiling())],
+            "<=" if partial.is_full() => vec![Comparator::new(Op::Le, partial.floor())],
+            "<=" => vec![Comparator::new(Op::Lt, partial.ceiling())],
+            "^" => vec![
+                Comparator::new(Op::Ge, partial.floor()),
+                Comparator::new(Op::Lt, partial.caret_ceiling()),
+            ],
+            "~" => vec![
+                Comparator::new(Op::Ge, partial.floor()),
+                Comparator::new(Op::Lt, partial.tilde_ceiling()),
+            ],
+            _ => return None,
+        };
+
+        Some(comparators)
+    }
+
+    /// The original expression
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Whether this is a bare version (e.g. `21` or `3.12.1`) rather than a range expression
+    pub fn is_plain(&self) -> bool {
+        self.plain.is_some()
+    }
+
+    /// Whether this is a bare, fully specified `major.minor.patch` version
+    pub fn is_exact(&self) -> bool {
+        self.plain.is_some_and(|p| p.is_full())
+    }
+
+    /// Vendor required by this constraint, if any (e.g. "corretto")
+    pub fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    /// The same constraint without its vendor requirement
+    pub fn without_vendor(&self) -> Self {
+        Self {
+            vendor: None,
+            ..self.clone()
+        }
+    }
+
+    /// Package variant required by this constraint, if any (e.g. "jre")
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    /// Pre-release tag required by this constraint, if any (e.g. "ea")
+    pub fn pre_release(&self) -> Option<&str> {
+        self.pre_release.as_deref()
+    }
+
+    /// Whether this constraint only accepts LTS versions
+    pub fn is_lts(&self) -> bool {
+        matches!(self.kind, ConstraintKind::Lts | ConstraintKind::LtsNamed(_))
+    }
+
+    /// Check whether a version satisfies this constraint
+    pub fn matches(&self, version: &ToolVersion) -> bool {
+        if let Some(vendor) = &self.vendor {
+            let same_vendor = version
+                .vendor
+                .as_deref()
+                .is_some_and(|v| v.eq_ignore_ascii_case(vendor));
+            if !same_vendor {
+                return false;
+            }
+        }
+
+        if self.variant.as_deref() != version.variant.as_deref() {
+            return false;
+        }
+
+        let same_channel = match (&self.pre_release, &version.pre_release) {
+            (None, None) => true,
+            (Some(wanted), Some(actual)) => Self::pre_release_matches(wanted, actual),
+            _ => false,
+        };
+        if !same_channel {
+            return false;
+        }
+
+        let components = (
+            version.major,
+            version.minor.unwrap_or(0),
+            version.patch.unwrap_or(0),
+        );
+
+        match &self.kind {
+            ConstraintKind::Any => true,
+            ConstraintKind::Lts => version.is_lts,
+            ConstraintKind::LtsNamed(name) => {
+                version.is_lts
+                    && version
+                        .metadata
+                        .as_deref()
+                        .and_then(|m| m.strip_prefix("lts:"))
+                        .is_some_and(|m| m.eq_ignore_ascii_case(name))
+            }
+            ConstraintKind::Ranges(alternatives) => alternatives
+                .iter()
+                .any(|set| set.iter().all(|c| c.matches(components))),
+        }
+    }
+
+    /// "ea" matches "ea", "ea.15" and "ea15"; "rc.1" only matches "rc.1"
+    fn pre_release_matches(wanted: &str, actual: &str) -> bool {
+        let actual = actual.to_lowercase();
+        if actual == wanted {
+            return true;
+        }
+        wanted.chars().all(|c| c.is_ascii_alphabetic())
+            && actual
+                .strip_prefix(wanted)
+                .is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+    }
+
+    /// Pick the highest version satisfying this constraint
+    pub fn best_match<'a, I>(&self, versions: I) -> Option<&'a ToolVersion>
+    where
+        I: IntoIterator<Item = &'a ToolVersion>,
+    {
+        self.best_match_by(versions, Ord::cmp)
+    }
+
+    /// Pick the highest version satisfying this constraint under `compare`
+    pub fn best_match_by<'a, I, F>(&self, versions: I, compare: F) -> Option<&'a ToolVersion>
+    where
+        I: IntoIterator<Item = &'a ToolVersion>,
+        F: Fn(&ToolVersion, &ToolVersion) -> Ordering,
+    {
+        versions
+            .into_iter()
+            .filter(|v| self.matches(v))
+            .max_by(|a, b| compare(a, b))
+    }
+}
+
+impl fmt::Display for VersionConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl std::str::FromStr for VersionConstraint {
+    type Err = JcvmError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(raw: &str) -> ToolVersion {
+        let parts: Vec<u32> = raw.split('.').map(|p| p.parse().unwrap()).collect();
+        ToolVersion::new(
+            raw.to_string(),
+            parts[0],
+            parts.get(1).copied(),
+            parts.get(2).copied(),
+        )
+    }
+
+    fn matches(expr: &str, version: &str) -> bool {
+        VersionConstraint::parse(expr).unwrap().matches(&v(version))
+    }
+
+    #[test]
+    fn partial_versions_match_on_components() {
+        assert!(matches("3.1", "3.1.4"));
+        assert!(!matches("3.1", "3.13.7"));
+        assert!(matches("21", "21.0.7"));
+        assert!(matches("21", "21"));
+        assert!(!matches("21", "22.0.1"));
+        assert!(matches("20.10.0", "20.10.0"));
+        assert!(!matches("20.10.0", "20.10.1"));
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(matches("21.x", "21.0.4"));
+        assert!(matches("3.12.*", "3.12.8"));
+        assert!(!matches("3.12.*", "3.13.0"));
+        assert!(matches("*", "1.0.0"));
+    }
+
+    #[test]
+    fn comparator_sets() {
+        assert!(matches(">=17 <22", "17.0.10"));
+        assert!(matches(">=17 <22", "21.0.4"));
+        assert!(!matches(">=17 <22", "22.0.0"));
+        assert!(!matches(">=17 <22", "11.0.22"));
+        assert!(matches(">=3.10,<3.13", "3.12.1"));
+        assert!(matches(">= 18", "20.1.0"));
+        assert!(!matches(">20", "20.9.0"));
+        assert!(matches("<=20", "20.9.0"));
+    }
+
+    #[test]
+    fn caret_and_tilde() {
+        assert!(matches("^20.10", "20.18.1"));
+        assert!(!matches("^20.10", "20.9.0"));
+        assert!(!matches("^20.10", "21.0.0"));
+        assert!(matches("~3.12", "3.12.8"));
+        assert!(!matches("~3.12", "3.13.0"));
+        assert!(matches("^0.2.3", "0.2.9"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+    }
+
+    #[test]
+    fn unions_and_hyphen_ranges() {
+        assert!(matches("^18 || ^20", "20.1.0"));
+        assert!(!matches("^18 || ^20", "19.1.0"));
+        assert!(matches("17 - 21", "21.0.4"));
+        assert!(!matches("17 - 21", "22.0.0"));
+    }
+
+    #[test]
+    fn lts_keyword() {
+        let constraint = VersionConstraint::parse("lts").unwrap();
+        assert!(constraint.is_lts());
+        assert!(constraint.matches(&v("20.10.0").with_lts(true)));
+        assert!(!constraint.matches(&v("21.1.0")));
+
+        let named = VersionConstraint::parse("lts/iron").unwrap();
+        let iron = v("20.10.0")
+            .with_lts(true)
+            .with_metadata("lts:Iron".to_string());
+        let hydrogen = v("18.19.0")
+            .with_lts(true)
+            .with_metadata("lts:Hydrogen".to_string());
+        assert!(named.matches(&iron));
+        assert!(!named.matches(&hydrogen));
+    }
+
+    #[test]
+    fn plain_and_exact_detection() {
+        assert!(VersionConstraint::parse("21").unwrap().is_plain());
+        assert!(!VersionConstraint::parse("21").unwrap().is_exact());
+        assert!(VersionConstraint::parse("17.0.10").unwrap().is_exact());
+        assert!(VersionConstraint::parse("v20.10.0").unwrap().is_exact());
+        assert!(!VersionConstraint::parse("21.x").unwrap().is_exact());
+        assert!(!VersionConstraint::parse("^20").unwrap().is_plain());
+        assert!(!VersionConstraint::parse("lts").unwrap().is_plain());
+    }
+
+    #[test]
+    fn best_match_picks_highest() {
+        let versions = vec![v("3.10.18"), v("3.13.7"), v("3.12.8"), v("3.12.1")];
+        let constraint = VersionConstraint::parse("~3.12").unwrap();
+        assert_eq!(constraint.best_match(&versions).unwrap().raw, "3.12.8");
+
+        let constraint = VersionConstraint::parse("3.1").unwrap();
+        assert!(constraint.best_match(&versions).is_none());
+    }
+
+    #[test]
+    fn vendor_prefixes() {
+        let corretto = v("17.0.12").with_vendor("corretto".to_string());
+        let temurin = v("17.0.12").with_vendor("temurin".to_string());
+        let legacy = v("17.0.12");
+
+        let constraint = VersionConstraint::parse("corretto-17").unwrap();
+        assert_eq!(constraint.vendor(), Some("corretto"));
+        assert!(constraint.is_plain());
+        assert!(constraint.matches(&corretto));
+        assert!(!constraint.matches(&temurin));
+        assert!(!constraint.matches(&legacy));
+
+        let any_vendor = VersionConstraint::parse("17").unwrap();
+        assert!(any_vendor.matches(&corretto));
+        assert!(any_vendor.matches(&legacy));
+
+        let range = VersionConstraint::parse("temurin->=17 <22").unwrap();
+        assert!(range.matches(&temurin));
+        assert!(!range.matches(&corretto));
+        assert!(range.without_vendor().matches(&corretto));
+    }
+
+    #[test]
+    fn variant_suffixes() {
+        let jre = v("21.0.4").with_variant("jre".to_string());
+        let jdk = v("21.0.4");
+
+        let constraint = VersionConstraint::parse("21-jre").unwrap();
+        assert_eq!(constraint.variant(), Some("jre"));
+        assert!(constraint.is_plain());
+        assert!(constraint.matches(&jre));
+        assert!(!constraint.matches(&jdk));
+
+        let default = VersionConstraint::parse("21").unwrap();
+        assert!(default.matches(&jdk));
+        assert!(!default.matches(&jre));
+
+        let both = VersionConstraint::parse("temurin-21-jre").unwrap();
+        assert_eq!(both.vendor(), Some("temurin"));
+        assert!(both.matches(&jre.clone().with_vendor("temurin".to_string())));
+        assert!(!both.matches(&jre));
+    }
+
+    #[test]
+    fn pre_releases_require_a_pre_release_constraint() {
+        let ea = v("24").with_pre_release("ea".to_string());
+        let ga = v("23.0.1");
+
+        let latest = VersionConstraint::parse("latest").unwrap();
+        assert_eq!(latest.best_match([&ea, &ga]), Some(&ga));
+        assert!(!VersionConstraint::parse("24").unwrap().matches(&ea));
+
+        let channel = VersionConstraint::parse("24-ea").unwrap();
+        assert_eq!(channel.pre_release(), Some("ea"));
+        assert_eq!(channel.variant(), None);
+        assert!(channel.matches(&ea));
+        assert!(!channel.matches(&ga));
+
+        let build = VersionConstraint::parse("24-ea+15").unwrap();
+        assert!(build.matches(&ea));
+
+        let jre = VersionConstraint::parse("24-ea-jre").unwrap();
+        assert_eq!(jre.variant(), Some("jre"));
+        assert!(jre.matches(&ea.clone().with_variant("jre".to_string())));
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        assert!(VersionConstraint::parse("").is_err());
+        assert!(VersionConstraint::parse(">=").is_err());
+        assert!(VersionConstraint::parse("corretto-").is_err());
+        assert!(VersionConstraint::parse("abc").is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A single change to the environment needed to activate a tool version.
+///
+/// Plugins describe *what* should change; shells, `exec` and env exports decide how to
+/// render it (quoting, path separators, syntax).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum EnvAction {
+    /// Set a variable to a value
+    Set { key: String, value: String },
+    /// Put a directory in front of a path list such as `PATH`
+    PrependPath { key: String, path: PathBuf },
+    /// Put a directory at the end of a path list
+    AppendPath { key: String, path: PathBuf },
+    /// Remove a variable
+    Unset { key: String },
+}
+
+impl EnvAction {
+    pub fn set(key: impl Into<String>, value: impl Into<String>) -> Self {
+        EnvAction::Set {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn prepend_path(key: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        EnvAction::PrependPath {
+            key: key.into(),
+            path: path.into(),
+        }
+    }
+
+    pub fn append_path(key: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        EnvAction::AppendPath {
+            key: key.into(),
+            path: path.into(),
+        }
+    }
+
+    pub fn unset(key: impl Into<String>) -> Self {
+        EnvAction::Unset { key: key.into() }
+    }
+
+    /// Name of the variable this action changes
+    pub fn key(&self) -> &str {
+        match self {
+            EnvAction::Set { key, .. }
+            | EnvAction::PrependPath { key, .. }
+            | EnvAction::AppendPath { key, .. }
+            | EnvAction::Unset { key } => key,
+        }
+    }
+}
+
+impl std::fmt::Display for EnvAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvAction::Set { key, value } => write!(f, "{}={}", key, value),
+            EnvAction::PrependPath { key, path } => {
+                write!(f, "{}={}{}${}", key, path.display(), PATH_SEPARATOR, key)
+            }
+            EnvAction::AppendPath { key, path } => {
+                write!(f, "{}=${}{}{}", key, key, PATH_SEPARATOR, path.display())
+            }
+            EnvAction::Unset { key } => write!(f, "unset {}", key),
+        }
+    }
+}
+
+/// Separator between entries of path-list variables on this OS
+pub const PATH_SEPARATOR: char = if cfg!(windows) { ';' } else { ':' };
+
+/// Apply actions in order on top of the values returned by `current`, returning the final
+/// value of every variable touched (`None` means the variable must be removed)
+pub fn resolve<F>(actions: &[EnvAction], current: F) -> Vec<(String, Option<String>)>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut values: HashMap<String, Option<String>> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+
+    for action in actions {
+        let key = action.key().to_string();
+        if !values.contains_key(&key) {
+            values.insert(key.clone(), current(&key));
+            order.push(key.clone());
+        }
+        let existing = values.get(&key).cloned().flatten();
+
+        let updated = match action {
+            EnvAction::Set { value, .. } => Some(value.clone()),
+            EnvAction::Unset { .. } => None,
+            EnvAction::PrependPath { path, .. } => Some(match existing {
+                Some(rest) if !rest.is_empty() => {
+                    format!("{}{}{}", path.display(), PATH_SEPARATOR, rest)
+                }
+                _ => path.display().to_string(),
+            }),
+            EnvAction::AppendPath { path, .. } => Some(match existing {
+                Some(rest) if !rest.is_empty() => {
+                    format!("{}{}{}", rest, PATH_SEPARATOR, path.display())
+                }
+                _ => path.display().to_string(),
+            }),
+        };
+        values.insert(key, updated);
+    }
+
+    order
+        .into_iter()
+        .map(|key| {
+            let value = values.remove(&key).flatten();
+            (key, value)
+        })
+        .collect()
+}
+
+/// Apply actions to a command about to be spawned, on top of this process' environment
+pub fn apply_to_command(actions: &[EnvAction], command: &mut std::process::Command) {
+    for (key, value) in resolve(actions, |key| std::env::var(key).ok()) {
+        match value {
+            Some(value) => command.env(key, value),
+            None => command.env_remove(key),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_actions_in_order() {
+        let actions = vec![
+            EnvAction::set("JAVA_HOME", "/jdk"),
+            EnvAction::prepend_path("PATH", "/jdk/bin"),
+            EnvAction::append_path("PATH", "/extra"),
+            EnvAction::prepend_path("LD_LIBRARY_PATH", "/jdk/lib"),
+            EnvAction::unset("JRE_HOME"),
+        ];
+        let current = |key: &str| match key {
+            "PATH" => Some("/usr/bin".to_string()),
+            "JRE_HOME" => Some("/old".to_string()),
+            _ => None,
+        };
+
+        let sep = PATH_SEPARATOR;
+        assert_eq!(
+            resolve(&actions, current),
+            vec![
+                ("JAVA_HOME".to_string(), Some("/jdk".to_string())),
+                (
+                    "PATH".to_string(),
+                    Some(format!("/jdk/bin{sep}/usr/bin{sep}/extra"))
+                ),
+                ("LD_LIBRARY_PATH".to_string(), Some("/jdk/lib".to_string())),
+                ("JRE_HOME".to_string(), None),
+            ]
+        );
+    }
+}
@@ -1,2 +1,4 @@
+pub mod constraint;
+pub mod env;
 pub mod plugin;
 pub mod traits;
@@ -1,6 +1,8 @@
+use crate::core::env::EnvAction;
 use crate::error::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::path::{Path, PathBuf};
 
 /// Generic tool information that any versioned tool must provide
@@ -37,11 +39,31 @@ pub struct ToolVersion {
     /// Patch version number (optional)
     pub patch: Option<u32>,
 
-    /// Additional version metadata (e.g., build number, pre-release tag)
+    /// Additional version metadata (e.g., LTS codename)
     pub metadata: Option<String>,
 
     /// Whether this is an LTS version (if applicable)
     pub is_lts: bool,
+
+    /// Pre-release tag (e.g., "rc.1", "rc1", "b2", "ea"); releases sort above pre-releases
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_release: Option<String>,
+
+    /// Build identifier (e.g., "9" for Java "21.0.3+9"); higher builds sort higher
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+
+    /// Vendor or distribution flavor (e.g., "temurin", "corretto" for Java)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+
+    /// Package variant (e.g., "jre" or "debugimage" for Java); `None` is the default package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+
+    /// Release date, when reported by the remote source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<chrono::NaiveDate>,
 }
 
 impl ToolVersion {
@@ -53,6 +75,11 @@ impl ToolVersion {
             patch,
             metadata: None,
             is_lts: false,
+            pre_release: None,
+            build: None,
+            vendor: None,
+            variant: None,
+            release_date: None,
         }
     }
 
@@ -65,6 +92,137 @@ impl ToolVersion {
         self.metadata = Some(metadata);
         self
     }
+
+    pub fn with_pre_release(mut self, pre_release: String) -> Self {
+        self.pre_release = Some(pre_release);
+        self
+    }
+
+    pub fn with_build(mut self, build: String) -> Self {
+        self.build = Some(build);
+        self
+    }
+
+    pub fn with_vendor(mut self, vendor: String) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    pub fn with_variant(mut self, variant: String) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn with_release_date(mut self, release_date: chrono::NaiveDate) -> Self {
+        self.release_date = Some(release_date);
+        self
+    }
+
+    /// Whether this version is a pre-release (rc, beta, early access, ...)
+    pub fn is_pre_release(&self) -> bool {
+        self.pre_release.is_some()
+    }
+}
+
+/// A single dot/dash separated piece of a pre-release or build tag
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier<'a> {
+    Numeric(u64),
+    Alpha(u8, &'a str),
+}
+
+/// Split a tag like "rc.1", "rc1" or "beta-2" into comparable identifiers.
+///
+/// Well-known pre-release labels are ranked so that
+/// `ea`/`alpha` < `beta` < `rc`, regardless of spelling (`a1`, `b2`, `rc1`).
+/// Plugins with other conventions override `ToolProvider::compare_pre_release`.
+fn identifiers(tag: &str) -> Vec<Identifier<'_>> {
+    let mut result = Vec::new();
+
+    for part in tag.split(['.', '-', '_', '+']).filter(|p| !p.is_empty()) {
+        let mut start = 0;
+        let bytes = part.as_bytes();
+        while start < bytes.len() {
+            let numeric = bytes[start].is_ascii_digit();
+            let end = bytes[start..]
+                .iter()
+                .position(|b| b.is_ascii_digit() != numeric)
+                .map(|offset| start + offset)
+                .unwrap_or(bytes.len());
+            let chunk = &part[start..end];
+
+            result.push(match chunk.parse::<u64>() {
+                Ok(n) if numeric => Identifier::Numeric(n),
+                _ => {
+                    let rank = match chunk.to_ascii_lowercase().as_str() {
+                        "dev" => 0,
+                        "ea" | "alpha" | "a" => 1,
+                        "beta" | "b" => 2,
+                        "pre" | "preview" => 3,
+                        "rc" | "c" => 4,
+                        _ => 5,
+                    };
+                    Identifier::Alpha(rank, chunk)
+                }
+            });
+            start = end;
+        }
+    }
+
+    result
+}
+
+/// Default ordering of pre-release tags and build identifiers
+pub fn compare_tags(a: &str, b: &str) -> Ordering {
+    identifiers(a).cmp(&identifiers(b))
+}
+
+impl ToolVersion {
+    /// Compare two versions, ordering pre-release tags and builds with the given functions
+    pub fn cmp_by<P, B>(&self, other: &Self, compare_pre_release: P, compare_build: B) -> Ordering
+    where
+        P: Fn(&str, &str) -> Ordering,
+        B: Fn(&str, &str) -> Ordering,
+    {
+        let components = |v: &Self| (v.major, v.minor.unwrap_or(0), v.patch.unwrap_or(0));
+
+        components(self)
+            .cmp(&components(other))
+            // A release sorts above any of its pre-releases
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre_release(a, b),
+            })
+            .then_with(|| match (&self.build, &other.build) {
+                (Some(a), Some(b)) => compare_build(a, b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            })
+            // Remaining tie-breakers keep the ordering consistent with `Eq`
+            .then_with(|| self.raw.cmp(&other.raw))
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+            .then_with(|| self.pre_release.cmp(&other.pre_release))
+            .then_with(|| self.build.cmp(&other.build))
+            .then_with(|| self.vendor.cmp(&other.vendor))
+            .then_with(|| self.variant.cmp(&other.variant))
+            .then_with(|| self.release_date.cmp(&other.release_date))
+            .then_with(|| self.metadata.cmp(&other.metadata))
+            .then_with(|| self.is_lts.cmp(&other.is_lts))
+    }
+}
+
+impl Ord for ToolVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_by(other, compare_tags, compare_tags)
+    }
+}
+
+impl PartialOrd for ToolVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl std::fmt::Display for ToolVersion {
@@ -199,6 +357,21 @@ pub trait ToolProvider: Send + Sync {
     /// List available versions from remote sources
     async fn list_remote_versions(&self, lts_only: bool) -> Result<Vec<ToolVersion>>;
 
+    /// List every release of a single major version, newest first.
+    ///
+    /// Plugins whose `list_remote_versions` only reports feature releases (like Java)
+    /// override this to list each patch build.
+    async fn list_remote_releases(&self, major: u32) -> Result<Vec<ToolVersion>> {
+        let mut versions: Vec<ToolVersion> = self
+            .list_remote_versions(false)
+            .await?
+            .into_iter()
+            .filter(|v| v.major == major)
+            .collect();
+        versions.sort_by(|a, b| self.compare_versions(b, a));
+        Ok(versions)
+    }
+
     /// Find distribution for a specific version
     async fn find_distribution(
         &self,
@@ -210,6 +383,26 @@ pub trait ToolProvider: Send + Sync {
     /// Parse version string into ToolVersion
     fn parse_version(&self, version_str: &str) -> Result<ToolVersion>;
 
+    /// Order two pre-release tags of the same version (e.g. "b2" < "rc1");
+    /// releases always sort above their pre-releases
+    fn compare_pre_release(&self, a: &str, b: &str) -> Ordering {
+        compare_tags(a, b)
+    }
+
+    /// Order two build identifiers of the same version (e.g. "9" < "10")
+    fn compare_build(&self, a: &str, b: &str) -> Ordering {
+        compare_tags(a, b)
+    }
+
+    /// Order two versions of this tool, using the plugin's tag and build ordering
+    fn compare_versions(&self, a: &ToolVersion, b: &ToolVersion) -> Ordering {
+        a.cmp_by(
+            b,
+            |x, y| self.compare_pre_release(x, y),
+            |x, y| self.compare_build(x, y),
+        )
+    }
+
     /// Validate if a directory contains a valid installation
     fn validate_installation(&self, path: &Path) -> Result<bool>;
 
@@ -217,8 +410,8 @@ pub trait ToolProvider: Send + Sync {
     #[allow(dead_code)]
     fn get_executable_paths(&self, install_path: &Path) -> Result<Vec<PathBuf>>;
 
-    /// Get environment variables that should be set for this tool
-    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<(String, String)>>;
+    /// Environment changes needed to activate the tool installed at `install_path`
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>>;
 }
 
 /// Trait for installing tool distributions
@@ -339,4 +532,50 @@ mod tests {
         let version = ToolVersion::new("21.0.7".to_string(), 21, Some(0), Some(7));
         assert_eq!(version.to_string(), "21.0.7");
     }
+
+    #[test]
+    fn test_tool_version_ordering() {
+        let build7 = ToolVersion::new("21.0.3+7".to_string(), 21, Some(0), Some(3))
+            .with_build("7".to_string());
+        let build9 = ToolVersion::new("21.0.3+9".to_string(), 21, Some(0), Some(3))
+            .with_build("9".to_string());
+        let build10 = ToolVersion::new("21.0.3+10".to_string(), 21, Some(0), Some(3))
+            .with_build("10".to_string());
+        assert!(build9 > build7);
+        assert!(build10 > build9);
+
+        let rc1 = ToolVersion::new("3.13.0rc1".to_string(), 3, Some(13), Some(0))
+            .with_pre_release("rc1".to_string());
+        let b2 = ToolVersion::new("3.13.0b2".to_string(), 3, Some(13), Some(0))
+            .with_pre_release("b2".to_string());
+        let final_release = ToolVersion::new("3.13.0".to_string(), 3, Some(13), Some(0));
+        let previous = ToolVersion::new("3.12.8".to_string(), 3, Some(12), Some(8));
+        assert!(b2 < rc1);
+        assert!(rc1 < final_release);
+        assert!(previous < b2);
+
+        let node_rc1 = ToolVersion::new("23.0.0-rc.1".to_string(), 23, Some(0), Some(0))
+            .with_pre_release("rc.1".to_string());
+        let node_rc10 = ToolVersion::new("23.0.0-rc.10".to_string(), 23, Some(0), Some(0))
+            .with_pre_release("rc.10".to_string());
+        assert!(node_rc1 < node_rc10);
+
+        let mut versions = vec![
+            rc1.clone(),
+            final_release.clone(),
+            previous.clone(),
+            b2.clone(),
+        ];
+        versions.sort();
+        assert_eq!(versions, vec![previous, b2, rc1, final_release]);
+    }
+
+    #[test]
+    fn test_tool_version_ordering_consistent_with_eq() {
+        let a = ToolVersion::new("21".to_string(), 21, None, None);
+        let b = ToolVersion::new("21.0.0".to_string(), 21, Some(0), Some(0));
+        assert_ne!(a, b);
+        assert_ne!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+    }
 }
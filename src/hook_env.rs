@@ -0,0 +1,182 @@
+//! Session-local activation for `jcvm hook-env`. On every directory change the shell hook
+//! asks for the environment of the new directory and applies only the difference; what
+//! jcvm changed is remembered in `__JCVM_HOOK_STATE` so it can be undone on leaving.
+
+use crate::core::env::{self, EnvAction, PATH_SEPARATOR};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Variable holding the [`HookState`] of the current shell session
+pub const HOOK_STATE_ENV: &str = "__JCVM_HOOK_STATE";
+
+/// What the last `hook-env` run changed in this shell session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookState {
+    /// Values variables had before jcvm set or removed them
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub previous: BTreeMap<String, Option<String>>,
+
+    /// Entries jcvm put into path lists such as `PATH`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub added_paths: BTreeMap<String, Vec<String>>,
+}
+
+impl HookState {
+    /// Parse the state variable; a missing or mangled value means nothing was changed
+    pub fn parse(raw: Option<&str>) -> Self {
+        raw.and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty() && self.added_paths.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Value a variable would have without the changes recorded in this state
+    fn original(&self, key: &str, current: &impl Fn(&str) -> Option<String>) -> Option<String> {
+        if let Some(previous) = self.previous.get(key) {
+            return previous.clone();
+        }
+        let value = current(key)?;
+        match self.added_paths.get(key) {
+            Some(added) => Some(remove_entries(&value, added)),
+            None => Some(value),
+        }
+    }
+}
+
+/// Changes taking the environment from `current` to `actions` applied on top of the
+/// original environment, and the state to record for the next run. With no actions, every
+/// recorded change is undone.
+pub fn diff<F>(state: &HookState, actions: &[EnvAction], current: F) -> (Vec<EnvAction>, HookState)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let original = |key: &str| state.original(key, &current);
+
+    let mut next = HookState::default();
+    for action in actions {
+        match action {
+            EnvAction::Set { key, .. } | EnvAction::Unset { key } => {
+                next.previous
+                    .entry(key.clone())
+                    .or_insert_with(|| original(key));
+            }
+            EnvAction::PrependPath { key, path } | EnvAction::AppendPath { key, path } => {
+                next.added_paths
+                    .entry(key.clone())
+                    .or_default()
+                    .push(path.display().to_string());
+            }
+        }
+    }
+
+    let mut target: BTreeMap<String, Option<String>> =
+        env::resolve(actions, original).into_iter().collect();
+    // Variables changed last time but not this time go back to their original values
+    for key in state.previous.keys().chain(state.added_paths.keys()) {
+        if !target.contains_key(key) {
+            target.insert(key.clone(), original(key));
+        }
+    }
+
+    let changes = target
+        .into_iter()
+        .filter(|(key, value)| current(key) != *value)
+        .map(|(key, value)| match value {
+            Some(value) => EnvAction::set(key, value),
+            None => EnvAction::unset(key),
+        })
+        .collect();
+
+    (changes, next)
+}
+
+/// Remove the first occurrence of each entry from a path list
+fn remove_entries(value: &str, entries: &[String]) -> String {
+    let mut parts: Vec<&str> = value.split(PATH_SEPARATOR).collect();
+    for entry in entries {
+        if let Some(idx) = parts.iter().position(|part| part == entry) {
+            parts.remove(idx);
+        }
+    }
+    parts.join(&PATH_SEPARATOR.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// Apply changes to a fake environment, as the shell would after eval
+    fn apply(env: &RefCell<HashMap<String, String>>, changes: &[EnvAction]) {
+        for change in changes {
+            match change {
+                EnvAction::Set { key, value } => {
+                    env.borrow_mut().insert(key.clone(), value.clone());
+                }
+                EnvAction::Unset { key } => {
+                    env.borrow_mut().remove(key);
+                }
+                _ => unreachable!("hook-env only emits set and unset"),
+            }
+        }
+    }
+
+    #[test]
+    fn entering_and_leaving_a_project_restores_the_environment() {
+        let sep = PATH_SEPARATOR;
+        let env = RefCell::new(HashMap::from([
+            ("PATH".to_string(), format!("/usr/bin{sep}/bin")),
+            ("JAVA_HOME".to_string(), "/opt/system-jdk".to_string()),
+        ]));
+        let current = |key: &str| env.borrow().get(key).cloned();
+        let project = vec![
+            EnvAction::set("JAVA_HOME", "/jcvm/java/21"),
+            EnvAction::prepend_path("PATH", "/jcvm/java/21/bin"),
+            EnvAction::set("MAVEN_OPTS", "-Xmx2g"),
+        ];
+
+        let (changes, state) = diff(&HookState::default(), &project, current);
+        apply(&env, &changes);
+        assert_eq!(current("JAVA_HOME").unwrap(), "/jcvm/java/21");
+        assert_eq!(
+            current("PATH").unwrap(),
+            format!("/jcvm/java/21/bin{sep}/usr/bin{sep}/bin")
+        );
+
+        // Running again in the same directory changes nothing
+        let (changes, state) = diff(&state, &project, current);
+        assert!(changes.is_empty());
+
+        // The user extends PATH meanwhile; leaving keeps that and drops only jcvm's entry
+        let path = format!("/home/me/bin{sep}{}", current("PATH").unwrap());
+        env.borrow_mut().insert("PATH".to_string(), path);
+        let (changes, state) = diff(&state, &[], current);
+        apply(&env, &changes);
+        assert!(state.is_empty());
+        assert_eq!(current("JAVA_HOME").unwrap(), "/opt/system-jdk");
+        assert_eq!(current("MAVEN_OPTS"), None);
+        assert_eq!(
+            current("PATH").unwrap(),
+            format!("/home/me/bin{sep}/usr/bin{sep}/bin")
+        );
+    }
+
+    #[test]
+    fn parses_missing_or_invalid_state_as_empty() {
+        assert!(HookState::parse(None).is_empty());
+        assert!(HookState::parse(Some("garbage")).is_empty());
+
+        let state = HookState {
+            previous: BTreeMap::from([("JAVA_HOME".to_string(), None)]),
+            ..HookState::default()
+        };
+        assert_eq!(HookState::parse(Some(&state.to_json())), state);
+    }
+}
@@ -279,7 +279,7 @@ impl Installer {
         }
 
         // Sort by version (newest first)
-        installed.sort_by(|a, b| b.version.major.cmp(&a.version.major));
+        installed.sort_by(|a, b| b.version.cmp(&a.version));
 
         Ok(installed)
     }
@@ -7,10 +7,13 @@ mod core;
 mod detect;
 mod download;
 mod error;
+mod hook_env;
 mod install;
 mod models;
 mod plugins;
+mod project;
 mod shell;
+mod shims;
 mod tool_manager;
 mod utils;
 mod version_manager;
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
@@ -83,6 +84,32 @@ impl Version {
     }
 }
 
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let components = |v: &Self| (v.major, v.minor.unwrap_or(0), v.patch.unwrap_or(0));
+
+        components(self)
+            .cmp(&components(other))
+            // Numeric builds ("+9" vs "+10") compare by value; a build sorts above none
+            .then_with(|| match (&self.build, &other.build) {
+                (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                },
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            })
+            // Keep the ordering consistent with `Eq` ("21" vs "21.0.0")
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.major)?;
@@ -262,6 +289,22 @@ mod tests {
         assert!(!build_specific.matches(&with_build));
     }
 
+    #[test]
+    fn test_version_ordering() {
+        let mut versions: Vec<Version> =
+            ["21.0.3+7", "17.0.10", "21.0.3+10", "21.0.3", "21.0.2+13"]
+                .iter()
+                .map(|v| v.parse().unwrap())
+                .collect();
+        versions.sort();
+
+        let sorted: Vec<String> = versions.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            sorted,
+            vec!["17.0.10", "21.0.2+13", "21.0.3", "21.0.3+7", "21.0.3+10"]
+        );
+    }
+
     #[test]
     fn test_lts_versions() {
         assert!(Version::new(21).is_lts());
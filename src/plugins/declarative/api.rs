@@ -0,0 +1,208 @@
+use super::spec::PluginSpec;
+use crate::error::{JcvmError, Result};
+use reqwest::Client;
+use serde::Deserialize;
+
+const GITHUB_API_BASE: &str = "https://api.github.com/repos";
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+}
+
+/// Fetches version lists and checksums for a declarative plugin
+pub struct DeclarativeApi {
+    client: Client,
+}
+
+impl DeclarativeApi {
+    pub fn new() -> Self {
+        Self {
+            client: Client::builder()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build()
+                .unwrap(),
+        }
+    }
+
+    /// List raw version strings (tags or index entries) from the spec's version source
+    pub async fn list_versions(&self, spec: &PluginSpec) -> Result<Vec<String>> {
+        let versions = match (&spec.versions.index_url, &spec.versions.github) {
+            (Some(url), _) => {
+                let body = self.get_text(url).await?;
+                Self::parse_index(&body, &spec.versions.version_key)
+            }
+            (None, Some(repo)) => self.list_github_tags(repo).await?,
+            (None, None) => Vec::new(),
+        };
+
+        Ok(versions)
+    }
+
+    /// Fetch the SHA-256 checksum for `file` from a checksum URL
+    pub async fn fetch_checksum(&self, url: &str, file: &str) -> Result<String> {
+        let body = self.get_text(url).await?;
+        Self::checksum_for(&body, url, file)
+    }
+
+    /// The checksum for `file` in the list fetched from `url`; a configured checksum
+    /// source that doesn't cover the file fails the install instead of skipping verification
+    fn checksum_for(body: &str, url: &str, file: &str) -> Result<String> {
+        Self::parse_checksum(body, file).ok_or_else(|| {
+            JcvmError::ConfigError(format!("Checksum list {} has no entry for {}", url, file))
+        })
+    }
+
+    async fn list_github_tags(&self, repo: &str) -> Result<Vec<String>> {
+        let mut tags = Vec::new();
+
+        for page in 1..=10 {
+            let url = format!(
+                "{}/{}/releases?per_page=100&page={}",
+                GITHUB_API_BASE, repo, page
+            );
+            let body = self.get_text(&url).await?;
+            let releases: Vec<GithubRelease> = serde_json::from_str(&body)?;
+            let count = releases.len();
+
+            tags.extend(
+                releases
+                    .into_iter()
+                    .filter(|r| !r.draft)
+                    .map(|r| r.tag_name),
+            );
+
+            if count < 100 {
+                break;
+            }
+        }
+
+        Ok(tags)
+    }
+
+    async fn get_text(&self, url: &str) -> Result<String> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| JcvmError::DownloadFailed {
+                url: url.to_string(),
+                source: e,
+            })?;
+
+        response
+            .text()
+            .await
+            .map_err(|e| JcvmError::DownloadFailed {
+                url: url.to_string(),
+                source: e,
+            })
+    }
+
+    /// A JSON array of strings or objects, or plain text with one version per line
+    fn parse_index(body: &str, version_key: &str) -> Vec<String> {
+        match serde_json::from_str::<Vec<serde_json::Value>>(body) {
+            Ok(entries) => entries
+                .into_iter()
+                .filter_map(|entry| match entry {
+                    serde_json::Value::String(s) => Some(s),
+                    serde_json::Value::Object(map) => map
+                        .get(version_key)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string),
+                    _ => None,
+                })
+                .collect(),
+            Err(_) => body
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Find the hash for `file` in a `sha256sum`-style list, or take the first
+    /// token of a single-hash file
+    fn parse_checksum(body: &str, file: &str) -> Option<String> {
+        let listed = body.lines().find_map(|line| {
+            let mut parts = line.split_whitespace();
+            let hash = parts.next()?;
+            let name = parts.next()?.trim_start_matches('*');
+            (name == file).then(|| hash.to_string())
+        });
+
+        listed.or_else(|| {
+            let mut lines = body.lines().filter(|l| !l.trim().is_empty());
+            let first = lines.next()?;
+            // A multi-entry list without our file is not a checksum for it
+            if lines.next().is_some() {
+                return None;
+            }
+            first.split_whitespace().next().map(str::to_string)
+        })
+    }
+}
+
+impl Default for DeclarativeApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_version_indexes() {
+        assert_eq!(
+            DeclarativeApi::parse_index(r#"["1.0.0", "1.1.0"]"#, "version"),
+            vec!["1.0.0", "1.1.0"]
+        );
+        assert_eq!(
+            DeclarativeApi::parse_index(r#"[{"name": "v2.0.0"}, {"other": 1}]"#, "name"),
+            vec!["v2.0.0"]
+        );
+        assert_eq!(
+            DeclarativeApi::parse_index("# versions\n1.0.0\n\n1.1.0\n", "version"),
+            vec!["1.0.0", "1.1.0"]
+        );
+    }
+
+    #[test]
+    fn parses_checksum_files() {
+        let list = "abc123  mytool-linux.tar.gz\ndef456 *mytool-darwin.tar.gz\n";
+        assert_eq!(
+            DeclarativeApi::parse_checksum(list, "mytool-darwin.tar.gz").as_deref(),
+            Some("def456")
+        );
+        assert_eq!(DeclarativeApi::parse_checksum(list, "other.zip"), None);
+        assert_eq!(
+            DeclarativeApi::parse_checksum("abc123\n", "mytool.zip").as_deref(),
+            Some("abc123")
+        );
+    }
+
+    #[test]
+    fn checksum_list_without_the_file_is_an_error() {
+        let url = "https://example.com/SHA256SUMS";
+        let list = "abc123  mytool-linux.tar.gz\ndef456 *mytool-darwin.tar.gz\n";
+        assert_eq!(
+            DeclarativeApi::checksum_for(list, url, "mytool-linux.tar.gz").unwrap(),
+            "abc123"
+        );
+
+        let err = DeclarativeApi::checksum_for(list, url, "mytool-windows.zip").unwrap_err();
+        assert!(err.to_string().contains("mytool-windows.zip"), "{}", err);
+        assert!(DeclarativeApi::checksum_for("", url, "mytool.zip").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::core::traits::{ArchiveType, InstalledTool, ToolDistribution};
+use crate::download::Downloader;
+use crate::error::{JcvmError, Result};
+use colored::*;
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use tar::Archive;
+
+/// Downloads and unpacks releases of a declarative plugin
+pub struct DeclarativeInstaller {
+    tool_id: String,
+    bin_dirs: Vec<String>,
+    cache_dir: PathBuf,
+    downloader: Downloader,
+}
+
+impl DeclarativeInstaller {
+    pub fn new(tool_id: String, bin_dirs: Vec<String>, cache_dir: PathBuf) -> Self {
+        Self {
+            tool_id,
+            bin_dirs,
+            cache_dir,
+            downloader: Downloader::new(),
+        }
+    }
+
+    pub async fn install(
+        &self,
+        distribution: &ToolDistribution,
+        dest_dir: &Path,
+    ) -> Result<InstalledTool> {
+        let version_str = distribution.version.to_string();
+
+        if dest_dir.exists() {
+            return Err(JcvmError::VersionAlreadyInstalled(
+                version_str,
+                dest_dir.display().to_string(),
+            ));
+        }
+
+        println!(
+            "{} {} {} for {}-{}",
+            "Installing".green().bold(),
+            self.tool_id,
+            version_str.cyan(),
+            distribution.platform.to_string().yellow(),
+            distribution.architecture.to_string().yellow()
+        );
+
+        let file_name = distribution
+            .download_url
+            .split('/')
+            .next_back()
+            .unwrap_or(&self.tool_id);
+
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let cache_file = self.cache_dir.join(file_name);
+
+        if !cache_file.exists() {
+            self.downloader
+                .download_with_progress(&distribution.download_url, &cache_file)
+                .await?;
+        } else {
+            println!("{}", "Using cached download".yellow());
+        }
+
+        if let Some(ref checksum) = distribution.checksum {
+            println!("{}", "Verifying checksum...".yellow());
+            if !Downloader::verify_checksum(&cache_file, checksum).await? {
+                std::fs::remove_file(&cache_file)?;
+                return Err(JcvmError::ChecksumMismatch {
+                    file: cache_file.display().to_string(),
+                });
+            }
+            println!("{} {}", "✓".green().bold(), "Checksum verified".green());
+        }
+
+        match &distribution.archive_type {
+            ArchiveType::TarGz => self.extract_tar_gz(&cache_file, dest_dir)?,
+            ArchiveType::Zip => self.extract_zip(&cache_file, dest_dir)?,
+            ArchiveType::Binary => self.install_binary(&cache_file, dest_dir)?,
+            other => {
+                return Err(JcvmError::ExtractionFailed(format!(
+                    "Unsupported archive format: {:?}",
+                    other
+                )))
+            }
+        }
+
+        let executable_path = self.find_executable(dest_dir);
+
+        println!(
+            "{} {} {} installed to {}",
+            "✓".green().bold(),
+            self.tool_id,
+            version_str.cyan(),
+            dest_dir.display().to_string().dimmed()
+        );
+
+        Ok(InstalledTool {
+            tool_id: self.tool_id.clone(),
+            version: distribution.version.clone(),
+            path: dest_dir.to_path_buf(),
+            installed_at: chrono::Utc::now(),
+            source: "plugin".to_string(),
+            executable_path,
+        })
+    }
+
+    pub async fn uninstall(&self, installed: &InstalledTool) -> Result<()> {
+        if !installed.path.exists() {
+            return Err(JcvmError::VersionNotFound(installed.version.to_string()));
+        }
+
+        std::fs::remove_dir_all(&installed.path)?;
+
+        println!(
+            "{} {} {} uninstalled successfully",
+            "✓".green().bold(),
+            installed.tool_id.cyan(),
+            installed.version.to_string().yellow()
+        );
+
+        Ok(())
+    }
+
+    /// Path of `<bin_dir>/<tool_id>` if the release ships an executable named after the tool
+    fn find_executable(&self, dest_dir: &Path) -> Option<PathBuf> {
+        let name = if cfg!(windows) {
+            format!("{}.exe", self.tool_id)
+        } else {
+            self.tool_id.clone()
+        };
+
+        self.bin_dirs
+            .iter()
+            .map(|dir| dest_dir.join(dir).join(&name))
+            .find(|path| path.exists())
+    }
+
+    fn temp_dir(dest_dir: &Path) -> Result<PathBuf> {
+        let parent = dest_dir.parent().ok_or_else(|| {
+            JcvmError::ExtractionFailed(format!("Invalid install path {}", dest_dir.display()))
+        })?;
+        let name = dest_dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("install");
+        let temp_dir = parent.join(format!(".tmp_{}", name));
+        if temp_dir.exists() {
+            std::fs::remove_dir_all(&temp_dir)?;
+        }
+        std::fs::create_dir_all(&temp_dir)?;
+        Ok(temp_dir)
+    }
+
+    /// Move the unpacked tree into place, unwrapping a single top-level directory
+    fn finish_extract(temp_dir: &Path, dest_dir: &Path) -> Result<()> {
+        let entries: Vec<_> = std::fs::read_dir(temp_dir)?
+            .filter_map(|e| e.ok())
+            .collect();
+
+        if entries.len() == 1 && entries[0].path().is_dir() {
+            std::fs::rename(entries[0].path(), dest_dir)?;
+            std::fs::remove_dir_all(temp_dir)?;
+        } else {
+            std::fs::rename(temp_dir, dest_dir)?;
+        }
+
+        Ok(())
+    }
+
+    fn extract_tar_gz(&self, archive_path: &Path, dest_dir: &Path) -> Result<()> {
+        let temp_dir = Self::temp_dir(dest_dir)?;
+        let mut archive = Archive::new(GzDecoder::new(File::open(archive_path)?));
+        archive.unpack(&temp_dir)?;
+        Self::finish_extract(&temp_dir, dest_dir)
+    }
+
+    fn extract_zip(&self, archive_path: &Path, dest_dir: &Path) -> Result<()> {
+        let temp_dir = Self::temp_dir(dest_dir)?;
+        let mut archive = zip::ZipArchive::new(File::open(archive_path)?)?;
+        archive.extract(&temp_dir)?;
+        Self::finish_extract(&temp_dir, dest_dir)
+    }
+
+    /// Place a bare executable at `<install>/<first bin dir>/<tool_id>`
+    fn install_binary(&self, binary_path: &Path, dest_dir: &Path) -> Result<()> {
+        let bin_dir = match self.bin_dirs.first() {
+            Some(dir) => dest_dir.join(dir),
+            None => dest_dir.to_path_buf(),
+        };
+        std::fs::create_dir_all(&bin_dir)?;
+
+        let name = if cfg!(windows) {
+            format!("{}.exe", self.tool_id)
+        } else {
+            self.tool_id.clone()
+        };
+        let target = bin_dir.join(name);
+        std::fs::copy(binary_path, &target)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn bare_binaries_land_in_the_first_bin_dir() {
+        let temp = tempdir().unwrap();
+        let binary = temp.path().join("mytool-linux-amd64");
+        std::fs::write(&binary, b"#!/bin/sh\n").unwrap();
+
+        let installer = DeclarativeInstaller::new(
+            "mytool".to_string(),
+            vec!["bin".to_string()],
+            temp.path().join("cache"),
+        );
+        let dest = temp.path().join("1.0.0");
+        installer.install_binary(&binary, &dest).unwrap();
+
+        assert_eq!(
+            installer.find_executable(&dest),
+            Some(dest.join("bin").join(if cfg!(windows) {
+                "mytool.exe"
+            } else {
+                "mytool"
+            }))
+        );
+    }
+}
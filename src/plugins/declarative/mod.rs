@@ -0,0 +1,384 @@
+//! Plugins defined by `<tool>.toml` files in the plugins directory (`~/.jcvm/plugins`).
+//!
+//! Each file is parsed into a [`PluginSpec`] and served by a generic [`DeclarativePlugin`],
+//! so internal CLIs can be managed without changes to jcvm itself.
+
+mod api;
+mod installer;
+mod spec;
+
+use crate::config::Config;
+use crate::core::env::EnvAction;
+use crate::core::plugin::PluginRegistry;
+use crate::core::traits::{
+    Architecture, ArchiveType, DetectedInstallation, InstalledTool, Platform, PluginMetadata,
+    ToolDetector, ToolDistribution, ToolInfo, ToolInstaller, ToolPlugin, ToolProvider, ToolVersion,
+};
+use crate::error::{JcvmError, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::warn;
+
+use api::DeclarativeApi;
+use installer::DeclarativeInstaller;
+pub use spec::PluginSpec;
+use spec::TemplateVars;
+
+/// A tool plugin driven entirely by a [`PluginSpec`]
+pub struct DeclarativePlugin {
+    spec: PluginSpec,
+    api: DeclarativeApi,
+    installer: DeclarativeInstaller,
+}
+
+impl DeclarativePlugin {
+    pub fn new(spec: PluginSpec, cache_dir: PathBuf) -> Self {
+        let installer =
+            DeclarativeInstaller::new(spec.id.clone(), spec.bin_dirs.clone(), cache_dir);
+        Self {
+            spec,
+            api: DeclarativeApi::new(),
+            installer,
+        }
+    }
+
+    pub fn metadata(&self) -> PluginMetadata {
+        PluginMetadata {
+            id: self.spec.id.clone(),
+            name: self.spec.display_name().to_string(),
+            version: self.spec.version.clone(),
+            author: self.spec.author.clone(),
+            platforms: self.spec.platforms(),
+            architectures: self.spec.architectures(),
+            category: self.spec.category.clone(),
+            builtin: false,
+        }
+    }
+
+    fn bin_paths(&self, install_path: &Path) -> Vec<PathBuf> {
+        self.spec
+            .bin_dirs
+            .iter()
+            .map(|dir| install_path.join(dir))
+            .collect()
+    }
+}
+
+/// Register every valid plugin definition in `config.plugins_dir()`.
+///
+/// Invalid files and IDs that clash with already registered plugins are skipped with a warning.
+pub fn register_plugins(registry: &PluginRegistry, config: &Config) -> Result<()> {
+    let dir = config.plugins_dir();
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("toml"))
+        .collect();
+    files.sort();
+
+    for path in files {
+        let spec = match PluginSpec::from_file(&path) {
+            Ok(spec) => spec,
+            Err(e) => {
+                warn!("Skipping plugin definition {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        let cache_dir = config.tool_cache_dir(&spec.id);
+        let plugin = DeclarativePlugin::new(spec, cache_dir);
+        let metadata = plugin.metadata();
+        if let Err(e) = registry.register(Arc::new(plugin), metadata) {
+            warn!("Skipping plugin definition {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse versions such as "1.2.3", "v2.0", "1.4.0-rc.1" or "0.9.1+build.5"
+pub(crate) fn parse_generic_version(version_str: &str) -> Result<ToolVersion> {
+    let cleaned = version_str.trim().trim_start_matches('v');
+
+    let (core, build) = match cleaned.split_once('+') {
+        Some((core, build)) => (core, Some(build)),
+        None => (cleaned, None),
+    };
+    let (core, pre_release) = match core.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (core, None),
+    };
+
+    let parts: Vec<&str> = core.split('.').collect();
+    let major = parts
+        .first()
+        .and_then(|p| p.parse::<u32>().ok())
+        .ok_or_else(|| JcvmError::InvalidVersion(version_str.to_string()))?;
+    let minor = parts.get(1).and_then(|p| p.parse::<u32>().ok());
+    let patch = parts.get(2).and_then(|p| p.parse::<u32>().ok());
+
+    let mut version = ToolVersion::new(cleaned.to_string(), major, minor, patch);
+    if let Some(pre) = pre_release.filter(|p| !p.is_empty()) {
+        version = version.with_pre_release(pre.to_string());
+    }
+    if let Some(build) = build.filter(|b| !b.is_empty()) {
+        version = version.with_build(build.to_string());
+    }
+    Ok(version)
+}
+
+#[async_trait]
+impl ToolProvider for DeclarativePlugin {
+    fn info(&self) -> ToolInfo {
+        ToolInfo {
+            id: self.spec.id.clone(),
+            name: self.spec.display_name().to_string(),
+            description: self.spec.description.clone(),
+            homepage: self.spec.homepage.clone(),
+            docs_url: self.spec.docs_url.clone(),
+        }
+    }
+
+    async fn list_remote_versions(&self, lts_only: bool) -> Result<Vec<ToolVersion>> {
+        let mut versions: Vec<ToolVersion> = self
+            .api
+            .list_versions(&self.spec)
+            .await?
+            .iter()
+            .filter_map(|raw| self.parse_version(raw).ok())
+            // Tools without an LTS notion treat stable releases as the "LTS" subset
+            .filter(|v| !lts_only || !v.is_pre_release())
+            .collect();
+
+        versions.sort_by(|a, b| self.compare_versions(b, a));
+        versions.dedup_by(|a, b| a.raw == b.raw);
+        Ok(versions)
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let unsupported = || JcvmError::UnsupportedPlatform {
+            os: platform.to_string(),
+            arch: arch.to_string(),
+        };
+        let os = self.spec.os_name(platform).ok_or_else(unsupported)?;
+        let arch_name = self.spec.arch_name(arch).ok_or_else(unsupported)?;
+        let ext = self.spec.extension(platform);
+
+        let download_url = self.spec.download_url(&TemplateVars {
+            version: &version.raw,
+            os: &os,
+            arch: &arch_name,
+            ext: &ext,
+        });
+
+        let checksum = match &self.spec.checksum {
+            Some(source) => {
+                let file = download_url.rsplit('/').next().unwrap_or_default();
+                let url = spec::render(
+                    &source.url,
+                    &TemplateVars {
+                        version: &version.raw,
+                        os: &os,
+                        arch: &arch_name,
+                        ext: &ext,
+                    },
+                )
+                .replace("{url}", &download_url)
+                .replace("{file}", file);
+                Some(self.api.fetch_checksum(&url, file).await?)
+            }
+            None => None,
+        };
+
+        let archive_type = match ext.as_str() {
+            "tar.gz" | "tgz" => ArchiveType::TarGz,
+            "zip" => ArchiveType::Zip,
+            "binary" | "exe" | "" => ArchiveType::Binary,
+            other => ArchiveType::Other(other.to_string()),
+        };
+
+        Ok(ToolDistribution {
+            tool_id: self.spec.id.clone(),
+            version: version.clone(),
+            platform,
+            architecture: arch,
+            download_url,
+            checksum,
+            size: None,
+            archive_type,
+            metadata: HashMap::new(),
+        })
+    }
+
+    fn parse_version(&self, version_str: &str) -> Result<ToolVersion> {
+        let prefix = self.spec.versions.tag_prefix.as_str();
+        let stripped = if prefix.is_empty() {
+            version_str
+        } else {
+            version_str.strip_prefix(prefix).unwrap_or(version_str)
+        };
+        parse_generic_version(stripped)
+    }
+
+    fn validate_installation(&self, path: &Path) -> Result<bool> {
+        Ok(self.bin_paths(path).iter().any(|dir| dir.is_dir()))
+    }
+
+    fn get_executable_paths(&self, install_path: &Path) -> Result<Vec<PathBuf>> {
+        let mut executables = Vec::new();
+        for dir in self.bin_paths(install_path) {
+            if let Ok(entries) = std::fs::read_dir(&dir) {
+                executables.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        }
+        executables.sort();
+        Ok(executables)
+    }
+
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>> {
+        let install_dir = install_path.display().to_string();
+        let mut vars: Vec<EnvAction> = self
+            .spec
+            .env
+            .iter()
+            .map(|(key, value)| EnvAction::set(key, value.replace("{install_dir}", &install_dir)))
+            .collect();
+
+        // Prepend in reverse so the first bin dir ends up first on PATH
+        vars.extend(
+            self.bin_paths(install_path)
+                .into_iter()
+                .rev()
+                .map(|dir| EnvAction::prepend_path("PATH", dir)),
+        );
+
+        Ok(vars)
+    }
+}
+
+#[async_trait]
+impl ToolInstaller for DeclarativePlugin {
+    async fn install(
+        &self,
+        distribution: &ToolDistribution,
+        dest_dir: &Path,
+    ) -> Result<InstalledTool> {
+        self.installer.install(distribution, dest_dir).await
+    }
+
+    async fn uninstall(&self, installed: &InstalledTool) -> Result<()> {
+        self.installer.uninstall(installed).await
+    }
+
+    async fn verify(&self, installed: &InstalledTool) -> Result<bool> {
+        Ok(installed.path.exists() && self.validate_installation(&installed.path)?)
+    }
+}
+
+#[async_trait]
+impl ToolDetector for DeclarativePlugin {
+    async fn detect_installations(&self) -> Result<Vec<DetectedInstallation>> {
+        Ok(Vec::new())
+    }
+
+    async fn import_installation(
+        &self,
+        _detected: &DetectedInstallation,
+        _dest_dir: &Path,
+    ) -> Result<InstalledTool> {
+        Err(JcvmError::PluginError {
+            plugin: self.spec.id.clone(),
+            message: "Importing existing installations is not supported for plugin-defined tools"
+                .to_string(),
+        })
+    }
+}
+
+impl ToolPlugin for DeclarativePlugin {
+    fn supports_platform(&self, platform: Platform, arch: Architecture) -> bool {
+        self.spec.os_name(platform).is_some() && self.spec.arch_name(arch).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn test_config(root: &Path) -> Config {
+        Config {
+            jcvm_dir: root.to_path_buf(),
+            versions_dir: root.join("versions"),
+            alias_dir: root.join("alias"),
+            cache_dir: root.join("cache"),
+            config_file: root.join("config.toml"),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn registers_plugins_from_directory() {
+        let temp = tempdir().unwrap();
+        let config = test_config(temp.path());
+        let plugins_dir = config.plugins_dir();
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+        std::fs::write(
+            plugins_dir.join("mytool.toml"),
+            r#"
+id = "mytool"
+[versions]
+index_url = "https://example.com/mytool/versions.json"
+[download]
+url = "https://example.com/mytool/{version}/mytool-{os}-{arch}.{ext}"
+os = { linux = "linux" }
+[download.ext]
+linux = "binary"
+[env]
+MYTOOL_HOME = "{install_dir}"
+"#,
+        )
+        .unwrap();
+        std::fs::write(plugins_dir.join("broken.toml"), "id = ").unwrap();
+
+        let registry = PluginRegistry::new();
+        register_plugins(&registry, &config).unwrap();
+        assert_eq!(registry.list_plugins().unwrap(), vec!["mytool".to_string()]);
+
+        let metadata = registry.get_metadata("mytool").unwrap();
+        assert!(!metadata.builtin);
+        assert_eq!(metadata.platforms, vec![Platform::Linux]);
+
+        let plugin = registry.get("mytool").unwrap();
+        assert!(plugin.supports_platform(Platform::Linux, Architecture::X64));
+        assert!(!plugin.supports_platform(Platform::Mac, Architecture::X64));
+
+        let vars = plugin
+            .get_environment_vars(Path::new("/opt/mytool/1.0.0"))
+            .unwrap();
+        assert_eq!(vars[0], EnvAction::set("MYTOOL_HOME", "/opt/mytool/1.0.0"));
+    }
+
+    #[test]
+    fn parses_generic_versions() {
+        let version = parse_generic_version("v1.4.0-rc.1+build.5").unwrap();
+        assert_eq!(version.raw, "1.4.0-rc.1+build.5");
+        assert_eq!(
+            (version.major, version.minor, version.patch),
+            (1, Some(4), Some(0))
+        );
+        assert_eq!(version.pre_release.as_deref(), Some("rc.1"));
+        assert_eq!(version.build.as_deref(), Some("build.5"));
+        assert!(parse_generic_version("latest").is_err());
+    }
+}
@@ -0,0 +1,381 @@
+use crate::core::traits::{Architecture, Platform, PluginCategory};
+use crate::error::{JcvmError, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A tool described by a `<tool>.toml` file in the plugins directory.
+///
+/// ```toml
+/// id = "mytool"
+/// name = "My Tool"
+///
+/// [versions]
+/// github = "acme/mytool"        # or: index_url = "https://example.com/versions.json"
+/// tag_prefix = "v"
+///
+/// [download]
+/// asset = "mytool-{version}-{os}-{arch}.{ext}"
+/// os = { mac = "darwin", linux = "linux" }
+/// arch = { x64 = "amd64", aarch64 = "arm64" }
+///
+/// [checksum]
+/// url = "https://github.com/acme/mytool/releases/download/v{version}/checksums.txt"
+///
+/// bin_dirs = ["bin"]
+///
+/// [env]
+/// MYTOOL_HOME = "{install_dir}"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginSpec {
+    /// Tool identifier used on the command line
+    pub id: String,
+
+    /// Display name (defaults to the ID)
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    pub homepage: Option<String>,
+
+    pub docs_url: Option<String>,
+
+    /// Version of the plugin definition itself
+    #[serde(default = "default_plugin_version")]
+    pub version: String,
+
+    #[serde(default = "default_author")]
+    pub author: String,
+
+    #[serde(default = "default_category")]
+    pub category: PluginCategory,
+
+    pub versions: VersionSource,
+
+    pub download: DownloadSpec,
+
+    pub checksum: Option<ChecksumSpec>,
+
+    /// Directories (relative to the install root) that hold executables
+    #[serde(default = "default_bin_dirs")]
+    pub bin_dirs: Vec<String>,
+
+    /// Environment variables to export; values may use `{install_dir}`
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+/// Where the list of available versions comes from
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VersionSource {
+    /// JSON array of version strings or objects, or plain text with one version per line
+    pub index_url: Option<String>,
+
+    /// Field holding the version when `index_url` returns objects
+    #[serde(default = "default_version_key")]
+    pub version_key: String,
+
+    /// GitHub repository ("owner/repo") whose releases are the versions
+    pub github: Option<String>,
+
+    /// Prefix stripped from tags / index entries (e.g. "v")
+    #[serde(default)]
+    pub tag_prefix: String,
+}
+
+/// How to build the download URL for a version and platform
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DownloadSpec {
+    /// Full URL template
+    pub url: Option<String>,
+
+    /// Release asset name template (requires `versions.github`)
+    pub asset: Option<String>,
+
+    /// Value of `{os}` per platform (mac, linux, windows); only listed platforms are supported
+    #[serde(default)]
+    pub os: BTreeMap<String, String>,
+
+    /// Value of `{arch}` per architecture (x64, aarch64, x86, arm); only listed ones are supported
+    #[serde(default)]
+    pub arch: BTreeMap<String, String>,
+
+    /// Value of `{ext}` per platform (defaults to "zip" on Windows and "tar.gz" elsewhere).
+    /// Use "binary" for a bare executable (and leave `{ext}` out of the template).
+    #[serde(default)]
+    pub ext: BTreeMap<String, String>,
+}
+
+/// Where the SHA-256 checksum of a download is published
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChecksumSpec {
+    /// URL template of either a single-hash file or a `sha256sum`-style list;
+    /// `{url}` and `{file}` refer to the download itself
+    pub url: String,
+}
+
+fn default_plugin_version() -> String {
+    "0.1.0".to_string()
+}
+
+fn default_author() -> String {
+    "unknown".to_string()
+}
+
+fn default_category() -> PluginCategory {
+    PluginCategory::Tool
+}
+
+fn default_bin_dirs() -> Vec<String> {
+    vec!["bin".to_string()]
+}
+
+fn default_version_key() -> String {
+    "version".to_string()
+}
+
+/// Values substituted into URL and asset templates
+pub struct TemplateVars<'a> {
+    pub version: &'a str,
+    pub os: &'a str,
+    pub arch: &'a str,
+    pub ext: &'a str,
+}
+
+impl PluginSpec {
+    /// Load and validate a plugin definition
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(&contents)
+    }
+
+    /// Parse and validate a plugin definition
+    pub fn parse(contents: &str) -> Result<Self> {
+        let spec: PluginSpec = toml::from_str(contents)?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |message: &str| JcvmError::PluginError {
+            plugin: self.id.clone(),
+            message: message.to_string(),
+        };
+
+        let valid_id = !self.id.is_empty()
+            && self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_id {
+            return Err(invalid(
+                "id must be non-empty and use only letters, digits, '-' or '_'",
+            ));
+        }
+
+        match (&self.versions.index_url, &self.versions.github) {
+            (Some(_), None) | (None, Some(_)) => {}
+            _ => {
+                return Err(invalid(
+                    "[versions] needs exactly one of index_url or github",
+                ))
+            }
+        }
+
+        match (&self.download.url, &self.download.asset) {
+            (Some(_), None) => {}
+            (None, Some(_)) if self.versions.github.is_some() => {}
+            (None, Some(_)) => return Err(invalid("download.asset requires versions.github")),
+            _ => return Err(invalid("[download] needs exactly one of url or asset")),
+        }
+
+        for key in self.download.os.keys().chain(self.download.ext.keys()) {
+            if platform_from_str(key).is_none() {
+                return Err(invalid(&format!("unknown platform '{}'", key)));
+            }
+        }
+        for key in self.download.arch.keys() {
+            if arch_from_str(key).is_none() {
+                return Err(invalid(&format!("unknown architecture '{}'", key)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Display name, falling back to the ID
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.id
+        } else {
+            &self.name
+        }
+    }
+
+    /// Platforms this tool can be downloaded for
+    pub fn platforms(&self) -> Vec<Platform> {
+        if self.download.os.is_empty() {
+            return vec![Platform::Mac, Platform::Linux, Platform::Windows];
+        }
+        self.download
+            .os
+            .keys()
+            .filter_map(|k| platform_from_str(k))
+            .collect()
+    }
+
+    /// Architectures this tool can be downloaded for
+    pub fn architectures(&self) -> Vec<Architecture> {
+        if self.download.arch.is_empty() {
+            return vec![Architecture::X64, Architecture::Aarch64];
+        }
+        self.download
+            .arch
+            .keys()
+            .filter_map(|k| arch_from_str(k))
+            .collect()
+    }
+
+    /// `{os}` value for a platform, if supported
+    pub fn os_name(&self, platform: Platform) -> Option<String> {
+        if self.download.os.is_empty() {
+            return Some(platform.as_str().to_string());
+        }
+        self.download.os.get(platform.as_str()).cloned()
+    }
+
+    /// `{arch}` value for an architecture, if supported
+    pub fn arch_name(&self, arch: Architecture) -> Option<String> {
+        if self.download.arch.is_empty() {
+            return matches!(arch, Architecture::X64 | Architecture::Aarch64)
+                .then(|| arch.as_str().to_string());
+        }
+        self.download.arch.get(arch.as_str()).cloned()
+    }
+
+    /// `{ext}` value for a platform
+    pub fn extension(&self, platform: Platform) -> String {
+        match self.download.ext.get(platform.as_str()) {
+            Some(ext) => ext.clone(),
+            None if platform == Platform::Windows => "zip".to_string(),
+            None => "tar.gz".to_string(),
+        }
+    }
+
+    /// Download URL for the given template values
+    pub fn download_url(&self, vars: &TemplateVars) -> String {
+        match (
+            &self.download.url,
+            &self.download.asset,
+            &self.versions.github,
+        ) {
+            (Some(url), _, _) => render(url, vars),
+            (None, Some(asset), Some(repo)) => format!(
+                "https://github.com/{}/releases/download/{}{}/{}",
+                repo,
+                self.versions.tag_prefix,
+                vars.version,
+                render(asset, vars)
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Substitute `{version}`, `{os}`, `{arch}` and `{ext}` in a template
+pub fn render(template: &str, vars: &TemplateVars) -> String {
+    template
+        .replace("{version}", vars.version)
+        .replace("{os}", vars.os)
+        .replace("{arch}", vars.arch)
+        .replace("{ext}", vars.ext)
+}
+
+fn platform_from_str(name: &str) -> Option<Platform> {
+    match name {
+        "mac" => Some(Platform::Mac),
+        "linux" => Some(Platform::Linux),
+        "windows" => Some(Platform::Windows),
+        _ => None,
+    }
+}
+
+fn arch_from_str(name: &str) -> Option<Architecture> {
+    match name {
+        "x64" => Some(Architecture::X64),
+        "aarch64" => Some(Architecture::Aarch64),
+        "x86" => Some(Architecture::X86),
+        "arm" => Some(Architecture::Arm),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+id = "mytool"
+name = "My Tool"
+
+[versions]
+github = "acme/mytool"
+tag_prefix = "v"
+
+[download]
+asset = "mytool-{version}-{os}-{arch}.{ext}"
+os = { mac = "darwin", linux = "linux" }
+arch = { x64 = "amd64", aarch64 = "arm64" }
+
+[env]
+MYTOOL_HOME = "{install_dir}"
+"#;
+
+    #[test]
+    fn parses_github_spec() {
+        let spec = PluginSpec::parse(SPEC).unwrap();
+        assert_eq!(spec.display_name(), "My Tool");
+        assert_eq!(spec.category, PluginCategory::Tool);
+        assert_eq!(spec.bin_dirs, vec!["bin"]);
+        assert_eq!(spec.platforms(), vec![Platform::Linux, Platform::Mac]);
+        assert_eq!(spec.os_name(Platform::Windows), None);
+
+        let vars = TemplateVars {
+            version: "1.2.3",
+            os: "darwin",
+            arch: "arm64",
+            ext: &spec.extension(Platform::Mac),
+        };
+        assert_eq!(
+            spec.download_url(&vars),
+            "https://github.com/acme/mytool/releases/download/v1.2.3/mytool-1.2.3-darwin-arm64.tar.gz"
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_specs() {
+        let no_source = r#"
+id = "mytool"
+[versions]
+[download]
+url = "https://example.com/{version}.tar.gz"
+"#;
+        assert!(PluginSpec::parse(no_source).is_err());
+
+        let asset_without_github = r#"
+id = "mytool"
+[versions]
+index_url = "https://example.com/versions.json"
+[download]
+asset = "mytool.tar.gz"
+"#;
+        assert!(PluginSpec::parse(asset_without_github).is_err());
+    }
+}
@@ -0,0 +1,500 @@
+//! Subprocess plugins living in directories under the plugins directory (`~/.jcvm/plugins`).
+//!
+//! A directory containing asdf's `bin/list-all` script is driven through the asdf plugin
+//! scripts (`list-all`, `download`, `install`, `uninstall`, `list-bin-paths`, `exec-env`),
+//! so existing asdf plugins can be cloned there as-is. A directory containing a `plugin`
+//! executable is called as `plugin <command>` with the same commands and environment.
+
+mod runner;
+
+use crate::config::Config;
+use crate::core::env::EnvAction;
+use crate::core::plugin::PluginRegistry;
+use crate::core::traits::{
+    Architecture, ArchiveType, DetectedInstallation, InstalledTool, Platform, PluginCategory,
+    PluginMetadata, ToolDetector, ToolDistribution, ToolInfo, ToolInstaller, ToolPlugin,
+    ToolProvider, ToolVersion,
+};
+use crate::error::{JcvmError, Result};
+use async_trait::async_trait;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::warn;
+
+pub use runner::Protocol;
+
+/// File in each install directory caching what `list-bin-paths` and `exec-env` reported
+const ENV_CACHE_FILE: &str = ".jcvm-env.json";
+
+/// Output of the environment scripts, computed once per installation
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct InstallEnv {
+    /// Executable directories relative to the install root
+    bin_paths: Vec<String>,
+
+    /// Variables exported by `exec-env`, `PATH` excluded
+    exec_env: Vec<(String, String)>,
+}
+
+/// A tool plugin implemented by external scripts or an executable
+pub struct ExternalPlugin {
+    id: String,
+    protocol: Protocol,
+    cache_dir: PathBuf,
+}
+
+impl ExternalPlugin {
+    pub fn new(id: String, protocol: Protocol, cache_dir: PathBuf) -> Self {
+        Self {
+            id,
+            protocol,
+            cache_dir,
+        }
+    }
+
+    pub fn metadata(&self) -> PluginMetadata {
+        PluginMetadata {
+            id: self.id.clone(),
+            name: self.id.clone(),
+            version: "0.0.0".to_string(),
+            author: format!("{} plugin", self.protocol.name()),
+            platforms: vec![Platform::Mac, Platform::Linux, Platform::Windows],
+            architectures: vec![
+                Architecture::X64,
+                Architecture::Aarch64,
+                Architecture::X86,
+                Architecture::Arm,
+            ],
+            category: PluginCategory::Tool,
+            builtin: false,
+        }
+    }
+
+    /// Variables passed to every command, following asdf's conventions
+    fn command_env(&self, version: &str, install_path: &Path) -> Vec<(String, String)> {
+        let concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+
+        let mut env = vec![
+            ("ASDF_INSTALL_TYPE".to_string(), "version".to_string()),
+            ("ASDF_INSTALL_VERSION".to_string(), version.to_string()),
+            (
+                "ASDF_INSTALL_PATH".to_string(),
+                install_path.display().to_string(),
+            ),
+            (
+                "ASDF_DOWNLOAD_PATH".to_string(),
+                self.cache_dir.join(version).display().to_string(),
+            ),
+            ("ASDF_CONCURRENCY".to_string(), concurrency.to_string()),
+        ];
+        if let Protocol::Asdf(dir) = &self.protocol {
+            env.push(("ASDF_PLUGIN_PATH".to_string(), dir.display().to_string()));
+        }
+        env
+    }
+
+    /// Version string of an install directory (install directories are named after the version)
+    fn installed_version(install_path: &Path) -> String {
+        install_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Run `list-bin-paths` (default "bin") and `exec-env` for an installation
+    fn compute_install_env(&self, install_path: &Path) -> Result<InstallEnv> {
+        let env = self.command_env(&Self::installed_version(install_path), install_path);
+
+        let bin_paths = if self.protocol.supports("list-bin-paths") {
+            match self.protocol.output(&self.id, "list-bin-paths", &env) {
+                Ok(output) => output.split_whitespace().map(str::to_string).collect(),
+                Err(e) => {
+                    warn!("{}", e);
+                    vec!["bin".to_string()]
+                }
+            }
+        } else {
+            vec!["bin".to_string()]
+        };
+
+        let exec_env = if self.protocol.supports("exec-env") {
+            self.protocol
+                .exec_env(&self.id, &env)?
+                .into_iter()
+                .filter(|(key, _)| key != "PATH")
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(InstallEnv {
+            bin_paths,
+            exec_env,
+        })
+    }
+
+    /// Compute an installation's environment and cache it in the install directory
+    fn write_install_env(&self, install_path: &Path) -> Result<InstallEnv> {
+        let install_env = self.compute_install_env(install_path)?;
+        let json = serde_json::to_string_pretty(&install_env)
+            .map_err(|e| JcvmError::ConfigError(e.to_string()))?;
+        std::fs::write(install_path.join(ENV_CACHE_FILE), json)?;
+        Ok(install_env)
+    }
+
+    /// Cached environment of an installation, computed on first use for older installs
+    fn install_env(&self, install_path: &Path) -> Result<InstallEnv> {
+        let cached = std::fs::read_to_string(install_path.join(ENV_CACHE_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok());
+        match cached {
+            Some(install_env) => Ok(install_env),
+            None => self.write_install_env(install_path),
+        }
+    }
+}
+
+/// Register every plugin directory in `config.plugins_dir()`.
+///
+/// Directories that clash with already registered plugins are skipped with a warning.
+pub fn register_plugins(registry: &PluginRegistry, config: &Config) -> Result<()> {
+    let dir = config.plugins_dir();
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(&dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+
+    for path in dirs {
+        let Some(protocol) = Protocol::detect(&path) else {
+            continue;
+        };
+        let Some(id) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_lowercase)
+        else {
+            continue;
+        };
+
+        let cache_dir = config.tool_cache_dir(&id);
+        let plugin = ExternalPlugin::new(id, protocol, cache_dir);
+        let metadata = plugin.metadata();
+        if let Err(e) = registry.register(Arc::new(plugin), metadata) {
+            warn!("Skipping plugin {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Versions are opaque strings to external plugins; numeric ones are also ordered numerically
+fn parse_external_version(version_str: &str) -> Result<ToolVersion> {
+    let raw = version_str.trim();
+    if raw.contains(char::is_whitespace) {
+        return Err(JcvmError::InvalidVersion(version_str.to_string()));
+    }
+    // Versions name install directories, so they must not escape `versions/<tool>/`
+    crate::config::check_version_name(raw)?;
+
+    let mut version = super::declarative::parse_generic_version(raw)
+        .unwrap_or_else(|_| ToolVersion::new(raw.to_string(), 0, None, None));
+    version.raw = raw.to_string();
+    Ok(version)
+}
+
+#[async_trait]
+impl ToolProvider for ExternalPlugin {
+    fn info(&self) -> ToolInfo {
+        ToolInfo {
+            id: self.id.clone(),
+            name: self.id.clone(),
+            description: format!("External {} plugin", self.protocol.name()),
+            homepage: None,
+            docs_url: None,
+        }
+    }
+
+    async fn list_remote_versions(&self, lts_only: bool) -> Result<Vec<ToolVersion>> {
+        let output = self.protocol.output(&self.id, "list-all", &[])?;
+
+        let mut versions: Vec<ToolVersion> = output
+            .split_whitespace()
+            .filter_map(|raw| parse_external_version(raw).ok())
+            // Without an LTS notion, stable releases stand in for the "LTS" subset
+            .filter(|v| !lts_only || !v.is_pre_release())
+            .collect();
+
+        versions.sort_by(|a, b| self.compare_versions(b, a));
+        versions.dedup_by(|a, b| a.raw == b.raw);
+        Ok(versions)
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        // The plugin's own scripts download the release; nothing to resolve up front
+        let mut metadata = HashMap::new();
+        metadata.insert("protocol".to_string(), self.protocol.name().to_string());
+
+        Ok(ToolDistribution {
+            tool_id: self.id.clone(),
+            version: version.clone(),
+            platform,
+            architecture: arch,
+            download_url: String::new(),
+            checksum: None,
+            size: None,
+            archive_type: ArchiveType::Other(self.protocol.name().to_string()),
+            metadata,
+        })
+    }
+
+    fn parse_version(&self, version_str: &str) -> Result<ToolVersion> {
+        parse_external_version(version_str)
+    }
+
+    fn validate_installation(&self, path: &Path) -> Result<bool> {
+        Ok(path.is_dir())
+    }
+
+    fn get_executable_paths(&self, install_path: &Path) -> Result<Vec<PathBuf>> {
+        // A failing `exec-env` doesn't stop the executables from being found
+        let bin_paths = match self.install_env(install_path) {
+            Ok(install_env) => install_env.bin_paths,
+            Err(e) => {
+                warn!("{}", e);
+                vec!["bin".to_string()]
+            }
+        };
+
+        let mut executables = Vec::new();
+        for dir in bin_paths {
+            if let Ok(entries) = std::fs::read_dir(install_path.join(dir)) {
+                executables.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        }
+        executables.sort();
+        Ok(executables)
+    }
+
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>> {
+        let install_env = self.install_env(install_path)?;
+        let mut vars: Vec<EnvAction> = install_env
+            .exec_env
+            .into_iter()
+            .map(|(key, value)| EnvAction::set(key, value))
+            .collect();
+
+        // Prepend in reverse so the first bin dir ends up first on PATH
+        vars.extend(
+            install_env
+                .bin_paths
+                .iter()
+                .rev()
+                .map(|dir| EnvAction::prepend_path("PATH", install_path.join(dir))),
+        );
+
+        Ok(vars)
+    }
+}
+
+#[async_trait]
+impl ToolInstaller for ExternalPlugin {
+    async fn install(
+        &self,
+        distribution: &ToolDistribution,
+        dest_dir: &Path,
+    ) -> Result<InstalledTool> {
+        let version = distribution.version.raw.clone();
+
+        if dest_dir.exists() {
+            return Err(JcvmError::VersionAlreadyInstalled(
+                version,
+                dest_dir.display().to_string(),
+            ));
+        }
+
+        println!(
+            "{} {} {} via {} plugin",
+            "Installing".green().bold(),
+            self.id,
+            version.cyan(),
+            self.protocol.name()
+        );
+
+        let env = self.command_env(&version, dest_dir);
+        let download_dir = self.cache_dir.join(&version);
+        std::fs::create_dir_all(&download_dir)?;
+        std::fs::create_dir_all(dest_dir)?;
+
+        let downloaded = if self.protocol.supports("download") {
+            self.protocol.run(&self.id, "download", &env)
+        } else {
+            Ok(())
+        };
+        let result = downloaded.and_then(|_| self.protocol.run(&self.id, "install", &env));
+
+        if let Err(e) = result {
+            let _ = std::fs::remove_dir_all(dest_dir);
+            return Err(e);
+        }
+
+        // Activation reads the cached result instead of running the scripts each time
+        if let Err(e) = self.write_install_env(dest_dir) {
+            warn!(
+                "Could not cache the {} {} environment: {}",
+                self.id, version, e
+            );
+        }
+
+        let executable_path = self
+            .get_executable_paths(dest_dir)?
+            .into_iter()
+            .find(|p| p.file_stem().and_then(|s| s.to_str()) == Some(self.id.as_str()));
+
+        println!(
+            "{} {} {} installed to {}",
+            "✓".green().bold(),
+            self.id,
+            version.cyan(),
+            dest_dir.display().to_string().dimmed()
+        );
+
+        Ok(InstalledTool {
+            tool_id: self.id.clone(),
+            version: distribution.version.clone(),
+            path: dest_dir.to_path_buf(),
+            installed_at: chrono::Utc::now(),
+            source: format!("{} plugin", self.protocol.name()),
+            executable_path,
+        })
+    }
+
+    async fn uninstall(&self, installed: &InstalledTool) -> Result<()> {
+        if !installed.path.exists() {
+            return Err(JcvmError::VersionNotFound(installed.version.to_string()));
+        }
+
+        if self.protocol.supports("uninstall") {
+            let env = self.command_env(&installed.version.raw, &installed.path);
+            self.protocol.run(&self.id, "uninstall", &env)?;
+        }
+        if installed.path.exists() {
+            std::fs::remove_dir_all(&installed.path)?;
+        }
+
+        println!(
+            "{} {} {} uninstalled successfully",
+            "✓".green().bold(),
+            installed.tool_id.cyan(),
+            installed.version.to_string().yellow()
+        );
+        Ok(())
+    }
+
+    async fn verify(&self, installed: &InstalledTool) -> Result<bool> {
+        Ok(installed.path.is_dir())
+    }
+}
+
+#[async_trait]
+impl ToolDetector for ExternalPlugin {
+    async fn detect_installations(&self) -> Result<Vec<DetectedInstallation>> {
+        Ok(Vec::new())
+    }
+
+    async fn import_installation(
+        &self,
+        _detected: &DetectedInstallation,
+        _dest_dir: &Path,
+    ) -> Result<InstalledTool> {
+        Err(JcvmError::PluginError {
+            plugin: self.id.clone(),
+            message: "Importing existing installations is not supported for external plugins"
+                .to_string(),
+        })
+    }
+}
+
+impl ToolPlugin for ExternalPlugin {
+    fn supports_platform(&self, _platform: Platform, _arch: Architecture) -> bool {
+        // External plugins decide for themselves and fail in `download`/`install` if needed
+        true
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::tempdir;
+
+    fn write_script(path: &Path, body: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn asdf_plugin_lists_and_installs() {
+        let temp = tempdir().unwrap();
+        let plugin_dir = temp.path().join("plugins").join("hello");
+        write_script(
+            &plugin_dir.join("bin/list-all"),
+            "echo 1.0.0 1.2.0 2.0.0-rc1 1.10.0 ../../x a/b",
+        );
+        write_script(
+            &plugin_dir.join("bin/install"),
+            "mkdir -p \"$ASDF_INSTALL_PATH/bin\" && echo \"$ASDF_INSTALL_VERSION\" > \"$ASDF_INSTALL_PATH/bin/hello\"",
+        );
+        write_script(
+            &plugin_dir.join("bin/exec-env"),
+            "export HELLO_HOME=\"$ASDF_INSTALL_PATH\"",
+        );
+
+        let protocol = Protocol::detect(&plugin_dir).unwrap();
+        assert_eq!(protocol.name(), "asdf");
+        let plugin = ExternalPlugin::new("hello".to_string(), protocol, temp.path().join("cache"));
+
+        let versions = plugin.list_remote_versions(true).await.unwrap();
+        let raws: Vec<&str> = versions.iter().map(|v| v.raw.as_str()).collect();
+        assert_eq!(raws, vec!["1.10.0", "1.2.0", "1.0.0"]);
+
+        let version = plugin.parse_version("1.2.0").unwrap();
+        let distribution = plugin
+            .find_distribution(&version, Platform::Linux, Architecture::X64)
+            .await
+            .unwrap();
+        let dest = temp.path().join("versions").join("1.2.0");
+        let installed = plugin.install(&distribution, &dest).await.unwrap();
+        assert_eq!(installed.executable_path, Some(dest.join("bin/hello")));
+
+        let env = plugin.get_environment_vars(&dest).unwrap();
+        assert!(env.contains(&EnvAction::set("HELLO_HOME", dest.display().to_string())));
+        assert_eq!(
+            env.last().unwrap(),
+            &EnvAction::prepend_path("PATH", dest.join("bin"))
+        );
+
+        // The scripts ran at install time; activation only reads the cached result
+        assert!(dest.join(ENV_CACHE_FILE).is_file());
+        std::fs::remove_file(plugin_dir.join("bin/exec-env")).unwrap();
+        write_script(&plugin_dir.join("bin/list-bin-paths"), "exit 1");
+        assert_eq!(plugin.get_environment_vars(&dest).unwrap(), env);
+    }
+}
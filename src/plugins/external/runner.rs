@@ -0,0 +1,159 @@
+use crate::error::{JcvmError, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// How an external plugin's commands are invoked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Protocol {
+    /// asdf layout: `<dir>/bin/list-all`, `<dir>/bin/download`, `<dir>/bin/install`, ...
+    Asdf(PathBuf),
+    /// A single executable called as `<path> <command>` (e.g. `plugin list-all`)
+    Executable(PathBuf),
+}
+
+impl Protocol {
+    /// Detect the protocol of a plugin directory, if it is one
+    pub fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("bin").join("list-all").is_file() {
+            return Some(Protocol::Asdf(dir.to_path_buf()));
+        }
+        let executable = dir.join(if cfg!(windows) {
+            "plugin.exe"
+        } else {
+            "plugin"
+        });
+        executable
+            .is_file()
+            .then_some(Protocol::Executable(executable))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Protocol::Asdf(_) => "asdf",
+            Protocol::Executable(_) => "executable",
+        }
+    }
+
+    /// Whether the plugin implements an optional command
+    pub fn supports(&self, command: &str) -> bool {
+        match self {
+            Protocol::Asdf(dir) => dir.join("bin").join(command).is_file(),
+            Protocol::Executable(_) => true,
+        }
+    }
+
+    fn command(&self, name: &str, env: &[(String, String)]) -> Command {
+        let mut cmd = match self {
+            Protocol::Asdf(dir) => Command::new(dir.join("bin").join(name)),
+            Protocol::Executable(path) => {
+                let mut cmd = Command::new(path);
+                cmd.arg(name);
+                cmd
+            }
+        };
+        cmd.envs(env.iter().map(|(k, v)| (k, v)));
+        cmd
+    }
+
+    /// Run a command and capture its stdout
+    pub fn output(&self, plugin: &str, name: &str, env: &[(String, String)]) -> Result<String> {
+        let output = self
+            .command(name, env)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| failed(plugin, name, &e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(failed(plugin, name, stderr.trim()));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Run a command with output streamed to the terminal
+    pub fn run(&self, plugin: &str, name: &str, env: &[(String, String)]) -> Result<()> {
+        let status = self
+            .command(name, env)
+            .status()
+            .map_err(|e| failed(plugin, name, &e.to_string()))?;
+
+        if !status.success() {
+            return Err(failed(plugin, name, &format!("exited with {}", status)));
+        }
+        Ok(())
+    }
+
+    /// Environment exported by `exec-env`.
+    ///
+    /// asdf's `bin/exec-env` is a shell script that is sourced, so it runs in `sh` and the
+    /// resulting environment is compared with the one it started from. The executable
+    /// protocol prints `KEY=VALUE` lines instead.
+    pub fn exec_env(
+        &self,
+        plugin: &str,
+        env: &[(String, String)],
+    ) -> Result<Vec<(String, String)>> {
+        match self {
+            Protocol::Asdf(dir) => {
+                let script = dir.join("bin").join("exec-env");
+                let output = Command::new("sh")
+                    .arg("-c")
+                    .arg(". \"$1\" >/dev/null 2>&1; env")
+                    .arg("sh")
+                    .arg(&script)
+                    .envs(env.iter().map(|(k, v)| (k, v)))
+                    .stdin(Stdio::null())
+                    .output()
+                    .map_err(|e| failed(plugin, "exec-env", &e.to_string()))?;
+
+                let before: BTreeMap<String, String> =
+                    std::env::vars().chain(env.iter().cloned()).collect();
+                Ok(parse_env(&String::from_utf8_lossy(&output.stdout))
+                    .into_iter()
+                    .filter(|(k, v)| k != "_" && k != "SHLVL" && before.get(k) != Some(v))
+                    .collect())
+            }
+            Protocol::Executable(_) => Ok(parse_env(&self.output(plugin, "exec-env", env)?)),
+        }
+    }
+}
+
+/// Parse `KEY=VALUE` lines, ignoring anything else
+pub fn parse_env(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            let valid =
+                !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            valid.then(|| (key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn failed(plugin: &str, command: &str, message: &str) -> JcvmError {
+    JcvmError::PluginError {
+        plugin: plugin.to_string(),
+        message: format!("'{}' failed: {}", command, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_env_lines() {
+        let env = parse_env("FOO=bar\nnot a var\nPATH=/a:/b\n=x\nURL=a=b\n");
+        assert_eq!(
+            env,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("PATH".to_string(), "/a:/b".to_string()),
+                ("URL".to_string(), "a=b".to_string()),
+            ]
+        );
+    }
+}
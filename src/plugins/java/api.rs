@@ -1,8 +1,8 @@
-use crate::core::traits::{Architecture, ArchiveType, Platform, ToolDistribution, ToolVersion};
+use super::vendors;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
 use crate::error::{JcvmError, Result};
 use reqwest::Client;
 use serde::Deserialize;
-use std::collections::HashMap;
 
 const ADOPTIUM_API_BASE: &str = "https://api.adoptium.net/v3";
 
@@ -15,6 +15,53 @@ struct AdoptiumRelease {
 #[derive(Debug, Deserialize)]
 struct AdoptiumAsset {
     binary: AdoptiumBinary,
+    version: AdoptiumVersion,
+}
+
+/// A release returned by the `/assets/version/{range}` and `/assets/feature_releases` endpoints
+#[derive(Debug, Deserialize)]
+struct AdoptiumVersionRelease {
+    binaries: Vec<AdoptiumBinary>,
+    version_data: AdoptiumVersion,
+    timestamp: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdoptiumVersion {
+    major: u32,
+    minor: u32,
+    security: u32,
+    build: Option<u32>,
+}
+
+impl AdoptiumVersion {
+    /// Version string in the form used for install directories (e.g. "21.0.4+7")
+    fn to_version_string(&self) -> String {
+        let base = format!("{}.{}.{}", self.major, self.minor, self.security);
+        match self.build {
+            Some(build) => format!("{}+{}", base, build),
+            None => base,
+        }
+    }
+
+    /// Version string of an early-access build (e.g. "24-ea+15", "21.0.5-ea+3")
+    fn to_ea_version_string(&self) -> String {
+        let base = if self.minor == 0 && self.security == 0 {
+            format!("{}-{}", self.major, super::EARLY_ACCESS)
+        } else {
+            format!(
+                "{}.{}.{}-{}",
+                self.major,
+                self.minor,
+                self.security,
+                super::EARLY_ACCESS
+            )
+        };
+        match self.build {
+            Some(build) => format!("{}+{}", base, build),
+            None => base,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -86,13 +133,87 @@ impl AdoptiumApi {
         Ok(release_info.available_lts_releases)
     }
 
-    /// Find download information for a specific version
+    /// List every GA release of a feature version (e.g. all 21.0.x builds), newest first
+    pub async fn list_feature_releases(&self, major: u32) -> Result<Vec<ToolVersion>> {
+        const PAGE_SIZE: usize = 50;
+        let mut versions = Vec::new();
+
+        for page in 0.. {
+            let url = format!(
+                "{}/assets/feature_releases/{}/ga?image_type=jdk&jvm_impl=hotspot&vendor=eclipse\
+                 &project=jdk&heap_size=normal&sort_order=DESC&page_size={}&page={}",
+                ADOPTIUM_API_BASE, major, PAGE_SIZE, page
+            );
+
+            let response =
+                self.client
+                    .get(&url)
+                    .send()
+                    .await
+                    .map_err(|e| JcvmError::DownloadFailed {
+                        url: url.clone(),
+                        source: e,
+                    })?;
+
+            // Paging past the last release answers 404
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                break;
+            }
+
+            let releases: Vec<AdoptiumVersionRelease> = response.json().await?;
+            let count = releases.len();
+
+            for release in releases {
+                let mut version = vendors::resolved_version(
+                    "temurin",
+                    &release.version_data.to_version_string(),
+                )?;
+                let released = release
+                    .timestamp
+                    .as_deref()
+                    .and_then(|t| t.get(..10))
+                    .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+                if let Some(date) = released {
+                    version = version.with_release_date(date);
+                }
+                versions.push(version);
+            }
+
+            if count < PAGE_SIZE {
+                break;
+            }
+        }
+
+        versions.sort_by(|a, b| b.cmp(a));
+        versions.dedup_by(|a, b| a.raw == b.raw);
+        Ok(versions)
+    }
+
+    /// Find download information for a specific version.
+    ///
+    /// A feature release ("21") resolves to the latest GA build. Anything more specific
+    /// ("17.0.10", "17.0.10+7") must exist exactly, otherwise `VersionNotFound` is returned.
     pub async fn find_distribution(
         &self,
         version: &ToolVersion,
         platform: Platform,
         arch: Architecture,
     ) -> Result<ToolDistribution> {
+        let image = vendors::image_type(version);
+        if matches!(image, "jdkfx" | "jrefx") {
+            return Err(vendors::unsupported_image("Eclipse Temurin", image));
+        }
+
+        if version.is_pre_release() {
+            return self
+                .find_early_access_distribution(version, platform, arch)
+                .await;
+        }
+
+        if version.minor.is_some() {
+            return self.find_exact_distribution(version, platform, arch).await;
+        }
+
         let url = format!(
             "{}/assets/latest/{}/hotspot",
             ADOPTIUM_API_BASE, version.major
@@ -120,37 +241,239 @@ impl AdoptiumApi {
             .find(|a| {
                 a.binary.os == os_name
                     && a.binary.architecture == arch_name
-                    && a.binary.image_type == "jdk"
+                    && a.binary.image_type == image
             })
             .ok_or_else(|| JcvmError::UnsupportedPlatform {
                 os: platform.to_string(),
                 arch: arch.to_string(),
             })?;
 
-        // Determine archive type from URL
-        let archive_type = if asset.binary.package.link.ends_with(".tar.gz") {
-            ArchiveType::TarGz
-        } else if asset.binary.package.link.ends_with(".zip") {
-            ArchiveType::Zip
-        } else if asset.binary.package.link.ends_with(".dmg") {
-            ArchiveType::Dmg
-        } else if asset.binary.package.link.ends_with(".exe") {
-            ArchiveType::Exe
-        } else {
-            ArchiveType::Other("unknown".to_string())
-        };
+        let resolved = vendors::resolved_version("temurin", &asset.version.to_version_string())?;
 
-        Ok(ToolDistribution {
-            tool_id: "java".to_string(),
-            version: version.clone(),
+        vendors::distribution(
+            "temurin",
+            resolved,
+            image,
             platform,
-            architecture: arch,
-            download_url: asset.binary.package.link,
-            checksum: asset.binary.package.checksum,
-            size: asset.binary.package.size,
-            archive_type,
-            metadata: HashMap::new(),
-        })
+            arch,
+            vendors::Package {
+                download_url: asset.binary.package.link,
+                checksum: asset.binary.package.checksum,
+                size: asset.binary.package.size,
+            },
+        )
+    }
+
+    /// Resolve an exact release through the version range endpoint
+    async fn find_exact_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = vendors::image_type(version);
+        let os_name = Self::platform_to_adoptium_os(platform);
+        let arch_name = Self::arch_to_adoptium_arch(arch);
+        let url = format!(
+            "{}/assets/version/{}?os={}&architecture={}&image_type={}&jvm_impl=hotspot\
+             &release_type=ga&vendor=eclipse&project=jdk&heap_size=normal\
+             &sort_order=DESC&page_size=50",
+            ADOPTIUM_API_BASE,
+            Self::encode_range(&Self::version_range(version)),
+            os_name,
+            arch_name,
+            image
+        );
+
+        let response =
+            self.client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| JcvmError::DownloadFailed {
+                    url: url.clone(),
+                    source: e,
+                })?;
+
+        // The API answers 404 when no release falls in the range
+        let releases: Vec<AdoptiumVersionRelease> =
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                Vec::new()
+            } else {
+                response.json().await?
+            };
+
+        let wanted_build = version.build.as_deref().and_then(|b| b.parse::<u32>().ok());
+
+        // Releases are sorted newest first; take the newest one matching every given component
+        let (release, binary) = releases
+            .into_iter()
+            .filter(|r| {
+                let v = &r.version_data;
+                v.major == version.major
+                    && version.minor.is_none_or(|minor| v.minor == minor)
+                    && version.patch.is_none_or(|patch| v.security == patch)
+                    && wanted_build.is_none_or(|build| v.build == Some(build))
+            })
+            .find_map(|mut r| {
+                let index = r.binaries.iter().position(|b| {
+                    b.os == os_name && b.architecture == arch_name && b.image_type == image
+                })?;
+                let binary = r.binaries.swap_remove(index);
+                Some((r, binary))
+            })
+            .ok_or_else(|| {
+                JcvmError::VersionNotFound(format!(
+                    "java@{} (no Temurin release {} for {}-{})",
+                    version.raw,
+                    vendors::version_without_vendor(version),
+                    platform,
+                    arch
+                ))
+            })?;
+
+        let resolved =
+            vendors::resolved_version("temurin", &release.version_data.to_version_string())?;
+
+        vendors::distribution(
+            "temurin",
+            resolved,
+            image,
+            platform,
+            arch,
+            vendors::Package {
+                download_url: binary.package.link,
+                checksum: binary.package.checksum,
+                size: binary.package.size,
+            },
+        )
+    }
+
+    /// Resolve the newest early-access build of a feature release ("24-ea"), or the
+    /// given build ("24-ea+15")
+    async fn find_early_access_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = vendors::image_type(version);
+        let os_name = Self::platform_to_adoptium_os(platform);
+        let arch_name = Self::arch_to_adoptium_arch(arch);
+        let url = format!(
+            "{}/assets/feature_releases/{}/ea?os={}&architecture={}&image_type={}\
+             &jvm_impl=hotspot&vendor=eclipse&project=jdk&heap_size=normal\
+             &sort_order=DESC&page_size=50",
+            ADOPTIUM_API_BASE, version.major, os_name, arch_name, image
+        );
+
+        let response =
+            self.client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| JcvmError::DownloadFailed {
+                    url: url.clone(),
+                    source: e,
+                })?;
+
+        let releases: Vec<AdoptiumVersionRelease> =
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                Vec::new()
+            } else {
+                response.json().await?
+            };
+
+        let wanted_build = version.build.as_deref().and_then(|b| b.parse::<u32>().ok());
+
+        let (release, binary) = releases
+            .into_iter()
+            .filter(|r| {
+                let v = &r.version_data;
+                version.minor.is_none_or(|minor| v.minor == minor)
+                    && version.patch.is_none_or(|patch| v.security == patch)
+                    && wanted_build.is_none_or(|build| v.build == Some(build))
+            })
+            .find_map(|mut r| {
+                let index = r.binaries.iter().position(|b| {
+                    b.os == os_name && b.architecture == arch_name && b.image_type == image
+                })?;
+                let binary = r.binaries.swap_remove(index);
+                Some((r, binary))
+            })
+            .ok_or_else(|| {
+                JcvmError::VersionNotFound(format!(
+                    "java@{} (no Temurin early-access build {} for {}-{})",
+                    version.raw,
+                    vendors::version_without_vendor(version),
+                    platform,
+                    arch
+                ))
+            })?;
+
+        let resolved =
+            vendors::resolved_version("temurin", &release.version_data.to_ea_version_string())?;
+
+        let mut distribution = vendors::distribution(
+            "temurin",
+            resolved,
+            image,
+            platform,
+            arch,
+            vendors::Package {
+                download_url: binary.package.link,
+                checksum: binary.package.checksum,
+                size: binary.package.size,
+            },
+        )?;
+        distribution
+            .metadata
+            .insert("release_type".to_string(), "ea".to_string());
+        Ok(distribution)
+    }
+
+    /// Maven-style version range for a requested version.
+    ///
+    /// "17.0.10+7" is matched exactly, "17.0.10" covers all of its builds and
+    /// "17.0" covers all of its updates.
+    fn version_range(version: &ToolVersion) -> String {
+        let minor = version.minor.unwrap_or(0);
+        match (version.patch, version.build.as_deref()) {
+            (Some(patch), Some(build)) => {
+                format!("{}.{}.{}+{}", version.major, minor, patch, build)
+            }
+            (Some(patch), None) => format!(
+                "[{}.{}.{},{}.{}.{})",
+                version.major,
+                minor,
+                patch,
+                version.major,
+                minor,
+                patch + 1
+            ),
+            (None, _) => format!(
+                "[{}.{}.0,{}.{}.0)",
+                version.major,
+                minor,
+                version.major,
+                minor + 1
+            ),
+        }
+    }
+
+    fn encode_range(range: &str) -> String {
+        range
+            .chars()
+            .map(|c| match c {
+                '[' => "%5B".to_string(),
+                ']' => "%5D".to_string(),
+                '(' => "%28".to_string(),
+                ')' => "%29".to_string(),
+                ',' => "%2C".to_string(),
+                '+' => "%2B".to_string(),
+                other => other.to_string(),
+            })
+            .collect()
     }
 
     fn platform_to_adoptium_os(platform: Platform) -> String {
@@ -175,3 +498,49 @@ impl Default for AdoptiumApi {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(raw: &str) -> ToolVersion {
+        crate::plugins::java::parse_java_version(raw).unwrap()
+    }
+
+    #[test]
+    fn version_ranges_match_requested_precision() {
+        assert_eq!(AdoptiumApi::version_range(&parse("17.0.10+7")), "17.0.10+7");
+        assert_eq!(
+            AdoptiumApi::version_range(&parse("17.0.10")),
+            "[17.0.10,17.0.11)"
+        );
+        assert_eq!(
+            AdoptiumApi::version_range(&parse("21.0")),
+            "[21.0.0,21.1.0)"
+        );
+        assert_eq!(
+            AdoptiumApi::encode_range("[17.0.10,17.0.11)"),
+            "%5B17.0.10%2C17.0.11%29"
+        );
+    }
+
+    #[test]
+    fn early_access_versions_are_pre_releases() {
+        let data = AdoptiumVersion {
+            major: 24,
+            minor: 0,
+            security: 0,
+            build: Some(15),
+        };
+        assert_eq!(data.to_ea_version_string(), "24-ea+15");
+
+        let version = vendors::resolved_version("temurin", &data.to_ea_version_string()).unwrap();
+        assert_eq!(version.raw, "temurin-24-ea+15");
+        assert!(version.is_pre_release());
+        assert!(!version.is_lts);
+        assert_eq!(
+            version.metadata.as_deref(),
+            Some(crate::plugins::java::EARLY_ACCESS_METADATA)
+        );
+    }
+}
@@ -212,7 +212,11 @@ impl crate::core::traits::ToolInstaller for JavaInstaller {
             version: distribution.version.clone(),
             path: dest_dir.to_path_buf(),
             installed_at: chrono::Utc::now(),
-            source: "adoptium".to_string(),
+            source: distribution
+                .metadata
+                .get("vendor")
+                .cloned()
+                .unwrap_or_else(|| "adoptium".to_string()),
             executable_path,
         })
     }
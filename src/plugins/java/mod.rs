@@ -1,7 +1,9 @@
 mod api;
 mod detector;
 mod installer;
+pub mod vendors;
 
+use crate::core::env::EnvAction;
 use crate::core::traits::{
     Architecture, DetectedInstallation, InstalledTool, Platform, PluginCategory, PluginMetadata,
     ToolDistribution, ToolInfo, ToolPlugin, ToolProvider, ToolVersion,
@@ -19,6 +21,7 @@ pub struct JavaPlugin {
     api: AdoptiumApi,
     installer: JavaInstaller,
     detector: JavaDetector,
+    default_vendor: &'static str,
 }
 
 impl JavaPlugin {
@@ -27,9 +30,19 @@ impl JavaPlugin {
             api: AdoptiumApi::new(),
             installer: JavaInstaller::new(),
             detector: JavaDetector::new(),
+            default_vendor: vendors::DEFAULT_VENDOR,
         }
     }
 
+    /// Use a different vendor for versions without a vendor prefix (e.g. "corretto").
+    /// Unknown vendors keep the default (Temurin).
+    pub fn with_default_vendor(mut self, vendor: &str) -> Self {
+        if let Some(vendor) = vendors::normalize_vendor(vendor) {
+            self.default_vendor = vendor;
+        }
+        self
+    }
+
     pub fn metadata() -> PluginMetadata {
         PluginMetadata {
             id: "java".to_string(),
@@ -50,6 +63,96 @@ impl Default for JavaPlugin {
     }
 }
 
+/// Pre-release tag of early-access builds ("24-ea")
+pub(crate) const EARLY_ACCESS: &str = "ea";
+
+/// `ToolVersion.metadata` marker for early-access builds
+pub(crate) const EARLY_ACCESS_METADATA: &str = "pre-release:ea";
+
+/// Parse a Java version such as "21", "17.0.10", "11.0.22+7", "24-ea+15", a
+/// vendor-prefixed version like "corretto-21.0.4.7.1" or an image variant like "21.0.4+7-jre"
+pub(crate) fn parse_java_version(version_str: &str) -> Result<ToolVersion> {
+    // Image type suffix: "temurin-21.0.4+7-jre" ("jdk" is the default and has no suffix)
+    let (version_str, image) = match version_str.rsplit_once('-') {
+        Some((rest, image)) if vendors::IMAGE_TYPES.contains(&image) => (rest, Some(image)),
+        _ => (version_str, None),
+    };
+    let image = image.filter(|i| *i != vendors::DEFAULT_IMAGE);
+
+    // Vendor prefix: "corretto-21.0.4.7.1", "temurin-21"
+    let (vendor, rest) = match version_str.split_once('-') {
+        Some((prefix, rest))
+            if !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            let vendor = vendors::normalize_vendor(prefix).ok_or_else(|| {
+                crate::error::JcvmError::InvalidVersion(format!(
+                    "{} (unknown vendor '{}'; supported: {})",
+                    version_str,
+                    prefix,
+                    vendors::VENDOR_IDS.join(", ")
+                ))
+            })?;
+            (Some(vendor), rest)
+        }
+        _ => (None, version_str),
+    };
+
+    let (core, build) = match rest.split_once('+') {
+        Some((core, build)) => (core, Some(build)),
+        None => (rest, None),
+    };
+    let (core, pre_release) = match core.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (core, None),
+    };
+
+    let parts: Vec<&str> = core.split('.').collect();
+
+    let major = parts
+        .first()
+        .and_then(|p| p.parse::<u32>().ok())
+        .ok_or_else(|| crate::error::JcvmError::InvalidVersion(version_str.to_string()))?;
+
+    let minor = parts.get(1).and_then(|p| p.parse::<u32>().ok());
+    let patch = parts.get(2).and_then(|p| p.parse::<u32>().ok());
+    // A fourth numeric component (e.g. "17.0.10.7") is also a build number
+    let build = build.or_else(|| parts.get(3).copied());
+
+    // Early-access builds of an LTS feature release are not LTS releases themselves
+    let is_lts = matches!(major, 8 | 11 | 17 | 21) && pre_release.is_none();
+
+    // Keep the canonical vendor name in the raw string ("adoptium-21" -> "temurin-21")
+    let mut raw = match vendor {
+        Some(vendor) => format!("{}-{}", vendor, rest),
+        None => version_str.to_string(),
+    };
+    if let Some(image) = image {
+        raw = format!("{}-{}", raw, image);
+    }
+    let mut version = ToolVersion::new(raw, major, minor, patch).with_lts(is_lts);
+
+    if let Some(vendor) = vendor {
+        version = version.with_vendor(vendor.to_string());
+    }
+
+    if let Some(image) = image {
+        version = version.with_variant(image.to_string());
+    }
+
+    if let Some(pre) = pre_release.filter(|p| !p.is_empty()) {
+        version = version.with_pre_release(pre.to_string());
+        if pre.eq_ignore_ascii_case(EARLY_ACCESS) {
+            version = version.with_metadata(EARLY_ACCESS_METADATA.to_string());
+        }
+    }
+
+    if let Some(build_num) = build.filter(|b| !b.is_empty()) {
+        version = version.with_build(build_num.to_string());
+    }
+
+    Ok(version)
+}
+
 #[async_trait]
 impl ToolProvider for JavaPlugin {
     fn info(&self) -> ToolInfo {
@@ -81,37 +184,34 @@ impl ToolProvider for JavaPlugin {
         }
     }
 
+    async fn list_remote_releases(&self, major: u32) -> Result<Vec<ToolVersion>> {
+        self.api.list_feature_releases(major).await
+    }
+
     async fn find_distribution(
         &self,
         version: &ToolVersion,
         platform: Platform,
         arch: Architecture,
     ) -> Result<ToolDistribution> {
-        self.api.find_distribution(version, platform, arch).await
+        // Early-access builds come from Temurin unless another vendor was named explicitly
+        let default_vendor = if version.is_pre_release() {
+            vendors::DEFAULT_VENDOR
+        } else {
+            self.default_vendor
+        };
+        let vendor = vendors::vendor(version.vendor.as_deref().unwrap_or(default_vendor))?;
+        if version.is_pre_release() && vendor.id() != "temurin" {
+            return Err(crate::error::JcvmError::VersionNotFound(format!(
+                "java@{} (early-access builds are only available from Eclipse Temurin)",
+                version.raw
+            )));
+        }
+        vendor.find_distribution(version, platform, arch).await
     }
 
     fn parse_version(&self, version_str: &str) -> Result<ToolVersion> {
-        // Parse versions like "21", "17.0.10", "11.0.22+7"
-        let parts: Vec<&str> = version_str.split(&['.', '+'][..]).collect();
-
-        let major = parts
-            .first()
-            .and_then(|p| p.parse::<u32>().ok())
-            .ok_or_else(|| crate::error::JcvmError::InvalidVersion(version_str.to_string()))?;
-
-        let minor = parts.get(1).and_then(|p| p.parse::<u32>().ok());
-        let patch = parts.get(2).and_then(|p| p.parse::<u32>().ok());
-        let build = parts.get(3).map(|p| p.to_string());
-
-        let is_lts = matches!(major, 8 | 11 | 17 | 21);
-        let mut version =
-            ToolVersion::new(version_str.to_string(), major, minor, patch).with_lts(is_lts);
-
-        if let Some(build_num) = build {
-            version = version.with_metadata(build_num);
-        }
-
-        Ok(version)
+        parse_java_version(version_str)
     }
 
     fn validate_installation(&self, path: &Path) -> Result<bool> {
@@ -157,7 +257,7 @@ impl ToolProvider for JavaPlugin {
         }
     }
 
-    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<(String, String)>> {
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>> {
         let java_home = if install_path.join("Contents/Home").exists() {
             install_path.join("Contents/Home")
         } else {
@@ -165,11 +265,8 @@ impl ToolProvider for JavaPlugin {
         };
 
         Ok(vec![
-            ("JAVA_HOME".to_string(), java_home.display().to_string()),
-            (
-                "PATH".to_string(),
-                format!("{}/bin:$PATH", java_home.display()),
-            ),
+            EnvAction::set("JAVA_HOME", java_home.display().to_string()),
+            EnvAction::prepend_path("PATH", java_home.join("bin")),
         ])
     }
 }
@@ -0,0 +1,109 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+
+const CORRETTO_RELEASES_API: &str = "https://api.github.com/repos/corretto";
+const CORRETTO_DOWNLOADS: &str = "https://corretto.aws/downloads/resources";
+
+/// Amazon Corretto builds; versions come from the corretto-<major> GitHub releases
+pub struct Corretto {
+    client: Client,
+}
+
+impl Corretto {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+
+    fn download_url(version: &str, platform: Platform, arch: Architecture) -> Option<String> {
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return None,
+        };
+
+        let file_name = match platform {
+            Platform::Linux => format!("amazon-corretto-{}-linux-{}.tar.gz", version, arch_name),
+            Platform::Mac => format!("amazon-corretto-{}-macosx-{}.tar.gz", version, arch_name),
+            Platform::Windows => {
+                format!("amazon-corretto-{}-windows-{}-jdk.zip", version, arch_name)
+            }
+        };
+
+        Some(format!("{}/{}/{}", CORRETTO_DOWNLOADS, version, file_name))
+    }
+}
+
+impl Default for Corretto {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Corretto {
+    fn id(&self) -> &'static str {
+        "corretto"
+    }
+
+    fn name(&self) -> &'static str {
+        "Amazon Corretto"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = super::image_type(version);
+        if image != super::DEFAULT_IMAGE {
+            return Err(super::unsupported_image(self.name(), image));
+        }
+
+        let url = format!(
+            "{}/corretto-{}/releases?per_page=100",
+            CORRETTO_RELEASES_API, version.major
+        );
+        let releases = super::get_github_releases(&self.client, &url).await?;
+
+        let candidates = releases
+            .into_iter()
+            .filter(|r| !r.prerelease)
+            .filter_map(|r| {
+                super::resolved_version(self.id(), &r.tag_name)
+                    .ok()
+                    .map(|v| (v, r))
+            })
+            .collect();
+
+        let (resolved, release) = super::best_candidate(version, candidates)
+            .ok_or_else(|| super::not_found(self.name(), version))?;
+
+        let download_url = Self::download_url(&release.tag_name, platform, arch)
+            .ok_or_else(|| super::unsupported(platform, arch))?;
+        // Each release's notes list the SHA-256 of every download
+        let file = download_url.rsplit('/').next().unwrap_or_default();
+        let checksum = release
+            .body
+            .as_deref()
+            .and_then(|notes| super::checksum_from_notes(notes, file));
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url,
+                checksum,
+                size: None,
+            },
+        )
+    }
+}
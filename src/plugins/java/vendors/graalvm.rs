@@ -0,0 +1,113 @@
+use super::{GithubRelease, JavaVendor};
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+
+const GRAALVM_RELEASES_API: &str =
+    "https://api.github.com/repos/graalvm/graalvm-ce-builds/releases?per_page=100";
+
+/// GraalVM Community Edition builds from the graalvm-ce-builds GitHub releases
+pub struct GraalVm {
+    client: Client,
+}
+
+impl GraalVm {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+
+    /// Version from a release tag such as "jdk-21.0.2"
+    fn tag_version(tag: &str) -> Option<&str> {
+        tag.strip_prefix("jdk-")
+    }
+}
+
+impl Default for GraalVm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for GraalVm {
+    fn id(&self) -> &'static str {
+        "graalvm"
+    }
+
+    fn name(&self) -> &'static str {
+        "GraalVM Community"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = super::image_type(version);
+        if image != super::DEFAULT_IMAGE {
+            return Err(super::unsupported_image(self.name(), image));
+        }
+
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "macos",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return Err(super::unsupported(platform, arch)),
+        };
+
+        let releases: Vec<GithubRelease> =
+            super::get_json(&self.client, GRAALVM_RELEASES_API).await?;
+
+        let candidates = releases
+            .into_iter()
+            .filter(|r| !r.prerelease)
+            .filter_map(|r| {
+                let resolved =
+                    super::resolved_version(self.id(), Self::tag_version(&r.tag_name)?).ok()?;
+                Some((resolved, r))
+            })
+            .collect();
+
+        let (resolved, release) = super::best_candidate(version, candidates)
+            .ok_or_else(|| super::not_found(self.name(), version))?;
+
+        let asset_name = format!(
+            "graalvm-community-jdk-{}_{}-{}_bin.{}",
+            super::version_without_vendor(&resolved),
+            os,
+            arch_name,
+            super::archive_extension(platform)
+        );
+        let asset = release
+            .assets
+            .into_iter()
+            .find(|a| a.name == asset_name)
+            .ok_or_else(|| super::unsupported(platform, arch))?;
+        let checksum = super::fetch_checksum(
+            &self.client,
+            &format!("{}.sha256", asset.browser_download_url),
+        )
+        .await;
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url: asset.browser_download_url,
+                checksum,
+                size: asset.size,
+            },
+        )
+    }
+}
@@ -0,0 +1,112 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+const LIBERICA_API: &str = "https://api.bell-sw.com/v1/liberica/releases";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LibericaRelease {
+    version: String,
+    download_url: String,
+    size: Option<u64>,
+    #[serde(default)]
+    ga: bool,
+}
+
+/// BellSoft Liberica builds resolved through the BellSoft releases API
+pub struct Liberica {
+    client: Client,
+}
+
+impl Liberica {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+}
+
+impl Default for Liberica {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Liberica {
+    fn id(&self) -> &'static str {
+        "liberica"
+    }
+
+    fn name(&self) -> &'static str {
+        "BellSoft Liberica"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "macos",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x86",
+            Architecture::Aarch64 => "arm",
+            _ => return Err(super::unsupported(platform, arch)),
+        };
+
+        let image = super::image_type(version);
+        let bundle_type = match image {
+            "jdk" => "jdk",
+            "jre" => "jre",
+            "jdkfx" => "jdk-full",
+            "jrefx" => "jre-full",
+            other => return Err(super::unsupported_image(self.name(), other)),
+        };
+
+        let url = format!(
+            "{}?version-feature={}&bitness=64&os={}&arch={}&package-type={}&bundle-type={}",
+            LIBERICA_API,
+            version.major,
+            os,
+            arch_name,
+            super::archive_extension(platform),
+            bundle_type
+        );
+        let releases: Vec<LibericaRelease> = super::get_json(&self.client, &url).await?;
+
+        let candidates = releases
+            .into_iter()
+            .filter(|r| r.ga)
+            .filter_map(|r| {
+                super::resolved_version(self.id(), &r.version)
+                    .ok()
+                    .map(|v| (v, r))
+            })
+            .collect();
+
+        let (resolved, release) = super::best_candidate(version, candidates)
+            .ok_or_else(|| super::not_found(self.name(), version))?;
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url: release.download_url,
+                checksum: None,
+                size: release.size,
+            },
+        )
+    }
+}
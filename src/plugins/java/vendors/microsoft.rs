@@ -0,0 +1,89 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+
+const MICROSOFT_DOWNLOADS: &str = "https://aka.ms/download-jdk";
+
+/// Microsoft Build of OpenJDK.
+///
+/// Microsoft doesn't publish a metadata API, so versions are resolved through the
+/// `aka.ms` download links: a feature release ("21") always points to the latest update.
+pub struct Microsoft {
+    client: Client,
+}
+
+impl Microsoft {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+}
+
+impl Default for Microsoft {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Microsoft {
+    fn id(&self) -> &'static str {
+        "microsoft"
+    }
+
+    fn name(&self) -> &'static str {
+        "Microsoft Build of OpenJDK"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = super::image_type(version);
+        if image != super::DEFAULT_IMAGE {
+            return Err(super::unsupported_image(self.name(), image));
+        }
+
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "macos",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return Err(super::unsupported(platform, arch)),
+        };
+
+        let requested = super::version_without_vendor(version);
+        let download_url = format!(
+            "{}/microsoft-jdk-{}-{}-{}.{}",
+            MICROSOFT_DOWNLOADS,
+            requested,
+            os,
+            arch_name,
+            super::archive_extension(platform)
+        );
+        let checksum =
+            super::fetch_checksum(&self.client, &format!("{}.sha256sum.txt", download_url)).await;
+        let resolved = super::resolved_version(self.id(), &requested)?;
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url,
+                checksum,
+                size: None,
+            },
+        )
+    }
+}
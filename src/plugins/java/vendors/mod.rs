@@ -0,0 +1,427 @@
+mod corretto;
+mod graalvm;
+mod liberica;
+mod microsoft;
+mod oracle;
+mod semeru;
+mod temurin;
+mod zulu;
+
+use crate::core::constraint::VersionConstraint;
+use crate::core::traits::{Architecture, ArchiveType, Platform, ToolDistribution, ToolVersion};
+use crate::error::{JcvmError, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+pub use corretto::Corretto;
+pub use graalvm::GraalVm;
+pub use liberica::Liberica;
+pub use microsoft::Microsoft;
+pub use oracle::Oracle;
+pub use semeru::Semeru;
+pub use temurin::Temurin;
+pub use zulu::Zulu;
+
+/// Vendor used when a version has no vendor prefix and none is configured
+pub const DEFAULT_VENDOR: &str = "temurin";
+
+/// Canonical vendor identifiers, in the order they are listed to users
+pub const VENDOR_IDS: &[&str] = &[
+    "temurin",
+    "corretto",
+    "zulu",
+    "liberica",
+    "semeru",
+    "microsoft",
+    "oracle",
+    "graalvm",
+];
+
+/// Image type installed when none is requested
+pub const DEFAULT_IMAGE: &str = "jdk";
+
+/// Supported image types; `jdkfx`/`jrefx` are JavaFX-bundled builds
+pub const IMAGE_TYPES: &[&str] = &["jdk", "jre", "debugimage", "staticlibs", "jdkfx", "jrefx"];
+
+/// A JDK vendor that publishes builds through a public metadata API or download site
+#[async_trait]
+pub trait JavaVendor: Send + Sync {
+    /// Canonical identifier used in version strings and install directories (e.g. "corretto")
+    fn id(&self) -> &'static str;
+
+    /// Human readable vendor name
+    fn name(&self) -> &'static str;
+
+    /// Resolve the download for a version of this vendor's JDK.
+    ///
+    /// The returned distribution carries the fully resolved, vendor-prefixed version
+    /// (e.g. "corretto-21.0.4.7.1") which becomes the install directory name.
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution>;
+}
+
+/// Map a vendor name or common alias to its canonical identifier
+pub fn normalize_vendor(name: &str) -> Option<&'static str> {
+    match name.to_lowercase().as_str() {
+        "temurin" | "adoptium" | "adoptopenjdk" => Some("temurin"),
+        "corretto" | "amazon" => Some("corretto"),
+        "zulu" | "azul" => Some("zulu"),
+        "liberica" | "bellsoft" => Some("liberica"),
+        "semeru" | "ibm" => Some("semeru"),
+        "microsoft" | "ms" => Some("microsoft"),
+        "oracle" => Some("oracle"),
+        "graalvm" | "graal" | "graalce" => Some("graalvm"),
+        _ => None,
+    }
+}
+
+/// Create the vendor implementation for a canonical identifier or alias
+pub fn vendor(name: &str) -> Result<Box<dyn JavaVendor>> {
+    let vendor: Box<dyn JavaVendor> = match normalize_vendor(name) {
+        Some("temurin") => Box::new(Temurin::new()),
+        Some("corretto") => Box::new(Corretto::new()),
+        Some("zulu") => Box::new(Zulu::new()),
+        Some("liberica") => Box::new(Liberica::new()),
+        Some("semeru") => Box::new(Semeru::new()),
+        Some("microsoft") => Box::new(Microsoft::new()),
+        Some("oracle") => Box::new(Oracle::new()),
+        Some("graalvm") => Box::new(GraalVm::new()),
+        _ => {
+            return Err(JcvmError::PluginError {
+                plugin: "java".to_string(),
+                message: format!(
+                    "Unknown Java vendor '{}'. Supported vendors: {}",
+                    name,
+                    VENDOR_IDS.join(", ")
+                ),
+            })
+        }
+    };
+    Ok(vendor)
+}
+
+/// Build a resolved, vendor-prefixed version from a vendor's version string
+pub(crate) fn resolved_version(vendor: &str, version: &str) -> Result<ToolVersion> {
+    super::parse_java_version(&format!("{}-{}", vendor, version))
+}
+
+/// Image type requested by a version ("jdk" unless a variant suffix was given)
+pub(crate) fn image_type(version: &ToolVersion) -> &str {
+    version.variant.as_deref().unwrap_or(DEFAULT_IMAGE)
+}
+
+/// Requested version without its vendor prefix and image suffix (e.g. "17.0.10+7")
+pub(crate) fn version_without_vendor(version: &ToolVersion) -> String {
+    let without_vendor = match &version.vendor {
+        Some(_) => version
+            .raw
+            .split_once('-')
+            .map(|(_, rest)| rest)
+            .unwrap_or(&version.raw),
+        None => &version.raw,
+    };
+
+    match &version.variant {
+        Some(variant) => without_vendor
+            .strip_suffix(&format!("-{}", variant))
+            .unwrap_or(without_vendor)
+            .to_string(),
+        None => without_vendor.to_string(),
+    }
+}
+
+pub(crate) fn unsupported_image(vendor: &str, image: &str) -> JcvmError {
+    JcvmError::PluginError {
+        plugin: "java".to_string(),
+        message: format!("{} does not publish '{}' images", vendor, image),
+    }
+}
+
+/// Pick the highest candidate satisfying the requested version.
+///
+/// An exact match on the vendor's own version string (e.g. "21.0.4.7.1") wins; otherwise the
+/// request is treated as a constraint, so "21" or "21.0" select the newest matching build.
+pub(crate) fn best_candidate<T>(
+    requested: &ToolVersion,
+    candidates: Vec<(ToolVersion, T)>,
+) -> Option<(ToolVersion, T)> {
+    let wanted = version_without_vendor(requested);
+    let constraint = VersionConstraint::parse(&wanted).ok()?;
+    let exact = candidates
+        .iter()
+        .position(|(v, _)| version_without_vendor(v) == wanted);
+
+    match exact {
+        Some(index) => candidates.into_iter().nth(index),
+        None => candidates
+            .into_iter()
+            .filter(|(v, _)| constraint.matches(v))
+            .max_by(|(a, _), (b, _)| a.cmp(b)),
+    }
+}
+
+/// A GitHub release as returned by the releases API
+#[derive(Debug, Deserialize)]
+pub(crate) struct GithubRelease {
+    pub tag_name: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub assets: Vec<GithubAsset>,
+    /// Release notes (Markdown)
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GithubAsset {
+    pub name: String,
+    pub browser_download_url: String,
+    pub size: Option<u64>,
+}
+
+pub(crate) fn http_client() -> Client {
+    Client::builder()
+        .user_agent(concat!(
+            env!("CARGO_PKG_NAME"),
+            "/",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .unwrap()
+}
+
+/// Fetch and deserialize a JSON document
+pub(crate) async fn get_json<T: DeserializeOwned>(client: &Client, url: &str) -> Result<T> {
+    Ok(get(client, url).await?.json().await?)
+}
+
+/// Most pages of GitHub releases to read; newer releases come first
+const MAX_RELEASE_PAGES: usize = 20;
+
+/// Fetch every page of a GitHub releases listing, following the `Link: rel="next"` header
+pub(crate) async fn get_github_releases(client: &Client, url: &str) -> Result<Vec<GithubRelease>> {
+    let mut releases = Vec::new();
+    let mut next = Some(url.to_string());
+
+    for _ in 0..MAX_RELEASE_PAGES {
+        let Some(url) = next.take() else { break };
+        let response = get(client, &url).await?;
+        next = response
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|link| link.to_str().ok())
+            .and_then(next_page);
+        releases.extend(response.json::<Vec<GithubRelease>>().await?);
+    }
+
+    Ok(releases)
+}
+
+/// URL of the `rel="next"` entry in a `Link` header
+fn next_page(link: &str) -> Option<String> {
+    link.split(',').find_map(|entry| {
+        let (url, params) = entry.split_once(';')?;
+        params
+            .split(';')
+            .any(|p| p.trim() == r#"rel="next""#)
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// SHA-256 listed for `file` in release notes that tabulate each download with its
+/// checksums (as Corretto's do)
+pub(crate) fn checksum_from_notes(notes: &str, file: &str) -> Option<String> {
+    notes
+        .lines()
+        .filter(|line| line.contains(file))
+        .flat_map(|line| line.split(|c: char| !c.is_ascii_hexdigit()))
+        .find(|token| token.len() == 64)
+        .map(str::to_lowercase)
+}
+
+async fn get(client: &Client, url: &str) -> Result<reqwest::Response> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| JcvmError::DownloadFailed {
+            url: url.to_string(),
+            source: e,
+        })?;
+
+    if !response.status().is_success() {
+        return Err(JcvmError::PluginError {
+            plugin: "java".to_string(),
+            message: format!("Request to {} failed: HTTP {}", url, response.status()),
+        });
+    }
+
+    Ok(response)
+}
+
+/// Fetch a published checksum file (`<hash>  <file>` or a bare hash), if available
+pub(crate) async fn fetch_checksum(client: &Client, url: &str) -> Option<String> {
+    let response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let body = response.text().await.ok()?;
+    body.split_whitespace().next().map(|hash| hash.to_string())
+}
+
+pub(crate) fn archive_type_for(url: &str) -> ArchiveType {
+    if url.ends_with(".tar.gz") || url.ends_with(".tgz") {
+        ArchiveType::TarGz
+    } else if url.ends_with(".zip") {
+        ArchiveType::Zip
+    } else if url.ends_with(".dmg") {
+        ArchiveType::Dmg
+    } else if url.ends_with(".pkg") {
+        ArchiveType::Pkg
+    } else if url.ends_with(".exe") || url.ends_with(".msi") {
+        ArchiveType::Exe
+    } else {
+        ArchiveType::Other("unknown".to_string())
+    }
+}
+
+/// Archive extension used for portable JDK builds on a platform
+pub(crate) fn archive_extension(platform: Platform) -> &'static str {
+    match platform {
+        Platform::Windows => "zip",
+        Platform::Mac | Platform::Linux => "tar.gz",
+    }
+}
+
+pub(crate) fn unsupported(platform: Platform, arch: Architecture) -> JcvmError {
+    JcvmError::UnsupportedPlatform {
+        os: platform.to_string(),
+        arch: arch.to_string(),
+    }
+}
+
+pub(crate) fn not_found(vendor: &str, version: &ToolVersion) -> JcvmError {
+    JcvmError::VersionNotFound(format!("java@{} ({})", version.raw, vendor))
+}
+
+/// Download details of a vendor package
+pub(crate) struct Package {
+    pub download_url: String,
+    pub checksum: Option<String>,
+    pub size: Option<u64>,
+}
+
+/// Assemble a distribution for a resolved vendor version.
+///
+/// The requested image type becomes part of the version (and so the install directory)
+/// and is recorded alongside the vendor in the distribution metadata.
+pub(crate) fn distribution(
+    vendor: &str,
+    version: ToolVersion,
+    image: &str,
+    platform: Platform,
+    arch: Architecture,
+    package: Package,
+) -> Result<ToolDistribution> {
+    let version = if image == DEFAULT_IMAGE {
+        version
+    } else {
+        super::parse_java_version(&format!("{}-{}", version.raw, image))?
+    };
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("vendor".to_string(), vendor.to_string());
+    metadata.insert("image_type".to_string(), image.to_string());
+
+    Ok(ToolDistribution {
+        tool_id: "java".to_string(),
+        version,
+        platform,
+        architecture: arch,
+        archive_type: archive_type_for(&package.download_url),
+        download_url: package.download_url,
+        checksum: package.checksum,
+        size: package.size,
+        metadata,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_vendor_aliases() {
+        assert_eq!(normalize_vendor("adoptium"), Some("temurin"));
+        assert_eq!(normalize_vendor("Corretto"), Some("corretto"));
+        assert_eq!(normalize_vendor("azul"), Some("zulu"));
+        assert_eq!(normalize_vendor("unknown"), None);
+    }
+
+    #[test]
+    fn every_vendor_id_has_an_implementation() {
+        for id in VENDOR_IDS {
+            let vendor = vendor(id).unwrap();
+            assert_eq!(vendor.id(), *id);
+        }
+        assert!(vendor("nope").is_err());
+    }
+
+    #[test]
+    fn resolved_versions_are_vendor_prefixed() {
+        let version = resolved_version("corretto", "21.0.4.7.1").unwrap();
+        assert_eq!(version.raw, "corretto-21.0.4.7.1");
+        assert_eq!(version.vendor.as_deref(), Some("corretto"));
+        assert_eq!(version.patch, Some(4));
+        assert_eq!(version_without_vendor(&version), "21.0.4.7.1");
+
+        let jre = super::super::parse_java_version("temurin-21.0.4+7-jre").unwrap();
+        assert_eq!(jre.variant.as_deref(), Some("jre"));
+        assert_eq!(image_type(&jre), "jre");
+        assert_eq!(version_without_vendor(&jre), "21.0.4+7");
+    }
+
+    #[test]
+    fn follows_github_next_links() {
+        let link = r#"<https://api.github.com/repositories/1/releases?per_page=100&page=3>; rel="next", <https://api.github.com/repositories/1/releases?per_page=100&page=5>; rel="last""#;
+        assert_eq!(
+            next_page(link).as_deref(),
+            Some("https://api.github.com/repositories/1/releases?per_page=100&page=3")
+        );
+        assert_eq!(
+            next_page(r#"<https://api.github.com/x?page=1>; rel="prev""#),
+            None
+        );
+    }
+
+    #[test]
+    fn reads_checksums_from_release_notes() {
+        let md5 = "0123456789abcdef0123456789abcdef";
+        let sha256 = "5f8f4c1e2a7c3b9d0e6f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d";
+        let notes = format!(
+            "|Platform|Type|Download Link|Checksum (MD5) / Checksum (SHA256)|\n\
+             |Linux x64|JDK|[amazon-corretto-21.0.4.7.1-linux-x64.tar.gz](https://corretto.aws/downloads/resources/21.0.4.7.1/amazon-corretto-21.0.4.7.1-linux-x64.tar.gz)|`{}` / `{}`|\n",
+            md5, sha256
+        );
+        assert_eq!(
+            checksum_from_notes(&notes, "amazon-corretto-21.0.4.7.1-linux-x64.tar.gz").as_deref(),
+            Some(sha256)
+        );
+        assert_eq!(
+            checksum_from_notes(&notes, "amazon-corretto-21.0.4.7.1-windows-x64-jdk.zip"),
+            None
+        );
+    }
+}
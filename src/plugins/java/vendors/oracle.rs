@@ -0,0 +1,174 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::{JcvmError, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+const ORACLE_DOWNLOADS: &str = "https://download.oracle.com/java";
+const ORACLE_RELEASES_API: &str = "https://java.oraclecloud.com/javaReleases";
+
+/// Oracle JDK builds from download.oracle.com.
+///
+/// A feature release ("21") resolves to its newest release through the Java Release API;
+/// a full version ("21.0.4") is downloaded from the archive as is.
+pub struct Oracle {
+    client: Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct JavaReleases {
+    #[serde(default)]
+    items: Vec<JavaRelease>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JavaRelease {
+    release_version: String,
+    release_type: Option<String>,
+}
+
+impl Oracle {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+
+    /// Newest public release of a feature version (e.g. "21.0.4" for 21)
+    async fn latest_release(&self, version: &ToolVersion) -> Result<String> {
+        let url = format!(
+            "{}?familyVersion={}&sortBy=releaseDate&sortOrder=DESC",
+            ORACLE_RELEASES_API, version.major
+        );
+        let releases: JavaReleases = super::get_json(&self.client, &url).await?;
+
+        // Bundle patch releases are only available to support customers
+        releases
+            .items
+            .into_iter()
+            .find(|r| r.release_type.as_deref() != Some("BPR"))
+            .map(|r| r.release_version)
+            .ok_or_else(|| super::not_found(self.name(), version))
+    }
+
+    /// Archive name of a requested release ("21.0.4", "17.0.4.1"); partial versions like
+    /// "21.0" don't name an archive
+    fn release_name(version: &ToolVersion) -> Result<String> {
+        if version.patch.is_none() {
+            return Err(JcvmError::InvalidVersion(format!(
+                "{} (Oracle JDK needs a full version like {}.0.4, or {} for the newest release)",
+                version.raw, version.major, version.major
+            )));
+        }
+        let requested = super::version_without_vendor(version);
+        Ok(requested.split('+').next().unwrap_or_default().to_string())
+    }
+
+    fn download_url(
+        major: u32,
+        release: &str,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Option<String> {
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "macos",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return None,
+        };
+
+        Some(format!(
+            "{}/{}/archive/jdk-{}_{}-{}_bin.{}",
+            ORACLE_DOWNLOADS,
+            major,
+            release,
+            os,
+            arch_name,
+            super::archive_extension(platform)
+        ))
+    }
+}
+
+impl Default for Oracle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Oracle {
+    fn id(&self) -> &'static str {
+        "oracle"
+    }
+
+    fn name(&self) -> &'static str {
+        "Oracle JDK"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let image = super::image_type(version);
+        if image != super::DEFAULT_IMAGE {
+            return Err(super::unsupported_image(self.name(), image));
+        }
+
+        // A feature release is resolved first so the install is named after the real version
+        let release = match version.minor {
+            None => self.latest_release(version).await?,
+            Some(_) => Self::release_name(version)?,
+        };
+        let download_url = Self::download_url(version.major, &release, platform, arch)
+            .ok_or_else(|| super::unsupported(platform, arch))?;
+        let checksum =
+            super::fetch_checksum(&self.client, &format!("{}.sha256", download_url)).await;
+        let resolved = super::resolved_version(self.id(), &release)?;
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url,
+                checksum,
+                size: None,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle(version: &str) -> ToolVersion {
+        crate::plugins::java::parse_java_version(&format!("oracle-{}", version)).unwrap()
+    }
+
+    #[test]
+    fn archive_urls_need_a_full_version() {
+        assert_eq!(Oracle::release_name(&oracle("21.0.4")).unwrap(), "21.0.4");
+        assert_eq!(
+            Oracle::release_name(&oracle("17.0.4.1")).unwrap(),
+            "17.0.4.1"
+        );
+        assert!(Oracle::release_name(&oracle("21.0")).is_err());
+
+        assert_eq!(
+            Oracle::download_url(21, "21.0.4", Platform::Linux, Architecture::X64).unwrap(),
+            "https://download.oracle.com/java/21/archive/jdk-21.0.4_linux-x64_bin.tar.gz"
+        );
+        assert!(Oracle::download_url(21, "21.0.4", Platform::Linux, Architecture::X86).is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+
+const SEMERU_RELEASES_API: &str = "https://api.github.com/repos/ibmruntimes";
+
+/// IBM Semeru Runtime (OpenJ9) builds from the ibmruntimes GitHub releases
+pub struct Semeru {
+    client: Client,
+}
+
+impl Semeru {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+
+    /// Version from a release tag such as "jdk-21.0.4+7_openj9-0.46.0"
+    fn tag_version(tag: &str) -> Option<&str> {
+        let version = tag.strip_prefix("jdk-")?;
+        Some(version.split('_').next().unwrap_or(version))
+    }
+}
+
+impl Default for Semeru {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Semeru {
+    fn id(&self) -> &'static str {
+        "semeru"
+    }
+
+    fn name(&self) -> &'static str {
+        "IBM Semeru"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "mac",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return Err(super::unsupported(platform, arch)),
+        };
+
+        let image = super::image_type(version);
+        if matches!(image, "jdkfx" | "jrefx") {
+            return Err(super::unsupported_image(self.name(), image));
+        }
+
+        let url = format!(
+            "{}/semeru{}-binaries/releases?per_page=100",
+            SEMERU_RELEASES_API, version.major
+        );
+        let releases = super::get_github_releases(&self.client, &url).await?;
+
+        let candidates = releases
+            .into_iter()
+            .filter(|r| !r.prerelease)
+            .filter_map(|r| {
+                let resolved =
+                    super::resolved_version(self.id(), Self::tag_version(&r.tag_name)?).ok()?;
+                Some((resolved, r))
+            })
+            .collect();
+
+        let (resolved, release) = super::best_candidate(version, candidates)
+            .ok_or_else(|| super::not_found(self.name(), version))?;
+
+        let prefix = format!("ibm-semeru-open-{}_{}_{}_", image, arch_name, os);
+        let extension = super::archive_extension(platform);
+        let asset = release
+            .assets
+            .into_iter()
+            .find(|a| a.name.starts_with(&prefix) && a.name.ends_with(extension))
+            .ok_or_else(|| super::unsupported(platform, arch))?;
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url: asset.browser_download_url,
+                checksum: None,
+                size: asset.size,
+            },
+        )
+    }
+}
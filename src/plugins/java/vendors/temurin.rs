@@ -0,0 +1,44 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use crate::plugins::java::AdoptiumApi;
+use async_trait::async_trait;
+
+/// Eclipse Temurin builds published through the Adoptium API
+pub struct Temurin {
+    api: AdoptiumApi,
+}
+
+impl Temurin {
+    pub fn new() -> Self {
+        Self {
+            api: AdoptiumApi::new(),
+        }
+    }
+}
+
+impl Default for Temurin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Temurin {
+    fn id(&self) -> &'static str {
+        "temurin"
+    }
+
+    fn name(&self) -> &'static str {
+        "Eclipse Temurin"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        self.api.find_distribution(version, platform, arch).await
+    }
+}
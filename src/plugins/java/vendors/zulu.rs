@@ -0,0 +1,141 @@
+use super::JavaVendor;
+use crate::core::traits::{Architecture, Platform, ToolDistribution, ToolVersion};
+use crate::error::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+const AZUL_METADATA_API: &str = "https://api.azul.com/metadata/v1/zulu/packages";
+
+#[derive(Debug, Deserialize)]
+struct ZuluPackage {
+    package_uuid: String,
+    download_url: String,
+    java_version: Vec<u32>,
+    openjdk_build_number: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZuluPackageDetails {
+    sha256_hash: Option<String>,
+    size: Option<u64>,
+}
+
+impl ZuluPackage {
+    fn version_string(&self) -> String {
+        let base = self
+            .java_version
+            .iter()
+            .take(3)
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        match self.openjdk_build_number {
+            Some(build) => format!("{}+{}", base, build),
+            None => base,
+        }
+    }
+}
+
+/// Azul Zulu builds resolved through the Azul metadata API
+pub struct Zulu {
+    client: Client,
+}
+
+impl Zulu {
+    pub fn new() -> Self {
+        Self {
+            client: super::http_client(),
+        }
+    }
+}
+
+impl Default for Zulu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl JavaVendor for Zulu {
+    fn id(&self) -> &'static str {
+        "zulu"
+    }
+
+    fn name(&self) -> &'static str {
+        "Azul Zulu"
+    }
+
+    async fn find_distribution(
+        &self,
+        version: &ToolVersion,
+        platform: Platform,
+        arch: Architecture,
+    ) -> Result<ToolDistribution> {
+        let os = match platform {
+            Platform::Linux => "linux",
+            Platform::Mac => "macos",
+            Platform::Windows => "windows",
+        };
+        let arch_name = match arch {
+            Architecture::X64 => "x64",
+            Architecture::Aarch64 => "aarch64",
+            _ => return Err(super::unsupported(platform, arch)),
+        };
+
+        let image = super::image_type(version);
+        let (package_type, javafx) = match image {
+            "jdk" => ("jdk", false),
+            "jre" => ("jre", false),
+            "jdkfx" => ("jdk", true),
+            "jrefx" => ("jre", true),
+            other => return Err(super::unsupported_image(self.name(), other)),
+        };
+
+        let url = format!(
+            "{}/?java_version={}&os={}&arch={}&archive_type={}&java_package_type={}\
+             &javafx_bundled={}&release_status=ga&availability_types=CA&page_size=100",
+            AZUL_METADATA_API,
+            version.major,
+            os,
+            arch_name,
+            super::archive_extension(platform),
+            package_type,
+            javafx
+        );
+        let packages: Vec<ZuluPackage> = super::get_json(&self.client, &url).await?;
+
+        let candidates = packages
+            .into_iter()
+            .filter_map(|p| {
+                super::resolved_version(self.id(), &p.version_string())
+                    .ok()
+                    .map(|v| (v, p))
+            })
+            .collect();
+
+        let (resolved, package) = super::best_candidate(version, candidates)
+            .ok_or_else(|| super::not_found(self.name(), version))?;
+
+        // Checksums are only exposed on the package details endpoint
+        let details_url = format!("{}/{}", AZUL_METADATA_API, package.package_uuid);
+        let details: Option<ZuluPackageDetails> =
+            super::get_json(&self.client, &details_url).await.ok();
+        let (checksum, size) = details
+            .map(|d| (d.sha256_hash, d.size))
+            .unwrap_or((None, None));
+
+        super::distribution(
+            self.id(),
+            resolved,
+            image,
+            platform,
+            arch,
+            super::Package {
+                download_url: package.download_url,
+                checksum,
+                size,
+            },
+        )
+    }
+}
@@ -1,3 +1,5 @@
+pub mod declarative;
+pub mod external;
 pub mod java;
 pub mod nodejs;
 pub mod python;
@@ -10,12 +12,21 @@ use crate::core::plugin::PluginRegistry;
 use crate::error::Result;
 use std::sync::Arc;
 
+/// Register the built-in plugins followed by the declarative (`<tool>.toml`) and
+/// external (`<tool>/`) plugins in `config.plugins_dir()`
+pub fn load_plugins(config: &Config) -> Result<PluginRegistry> {
+    let registry = load_builtin_plugins(config)?;
+    declarative::register_plugins(&registry, config)?;
+    external::register_plugins(&registry, config)?;
+    Ok(registry)
+}
+
 /// Register the built-in plugins (Java, Node.js, Python)
 pub fn load_builtin_plugins(config: &Config) -> Result<PluginRegistry> {
     let registry = PluginRegistry::new();
 
     registry.register(
-        Arc::new(java::JavaPlugin::new()),
+        Arc::new(java::JavaPlugin::new().with_default_vendor(&config.default_distribution)),
         java::JavaPlugin::metadata(),
     )?;
     registry.register(
@@ -50,8 +50,8 @@ impl NodeJsApi {
             .map(|r| self.parse_release_version(&r.version, &r.lts))
             .collect::<Result<Vec<_>>>()?;
 
-        // Deduplicate and sort by major version
-        versions.sort_by(|a, b| b.major.cmp(&a.major));
+        // Sort newest first, then keep the latest release of each major version
+        versions.sort_by(|a, b| b.cmp(a));
         versions.dedup_by(|a, b| a.major == b.major);
 
         Ok(versions)
@@ -1,9 +1,12 @@
+use crate::core::constraint::VersionConstraint;
 use crate::core::traits::{DetectedInstallation, InstalledTool, ToolVersion};
 use crate::error::{JcvmError, Result};
+use crate::project::VersionRequest;
 use async_trait::async_trait;
 use colored::*;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use tracing::debug;
 
 pub struct NodeJsDetector;
 
@@ -12,32 +15,18 @@ impl NodeJsDetector {
         Self
     }
 
-    /// Reads .nvmrc or .node-version file in the given directory
-    /// These files are used by nvm and other Node version managers
+    /// Reads the nearest .nvmrc, .node-version or package.json (Volta pin or engines
+    /// range), starting in the given directory and walking up to the filesystem root
+    /// (or `JCVM_PROJECT_ROOT`). These files are used by nvm, Volta and npm
     pub fn read_node_version_file(&self, directory: &std::path::Path) -> Option<String> {
-        // Try .nvmrc first (most common)
-        let nvmrc_path = directory.join(".nvmrc");
-        if nvmrc_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&nvmrc_path) {
-                let version = content.trim().to_string();
-                if !version.is_empty() {
-                    return Some(version);
-                }
-            }
-        }
-
-        // Try .node-version (alternative)
-        let node_version_path = directory.join(".node-version");
-        if node_version_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&node_version_path) {
-                let version = content.trim().to_string();
-                if !version.is_empty() {
-                    return Some(version);
-                }
-            }
-        }
+        self.find_node_version_file(directory).map(|r| r.version)
+    }
 
-        None
+    /// Like `read_node_version_file`, but also returns the file that won
+    pub fn find_node_version_file(&self, directory: &std::path::Path) -> Option<VersionRequest> {
+        crate::project::find_version_file(directory, "node")
+            .ok()
+            .flatten()
     }
 
     /// Finds installation matching a version file in the given directory
@@ -47,19 +36,28 @@ impl NodeJsDetector {
     ) -> Result<Option<DetectedInstallation>> {
         use crate::core::traits::ToolDetector;
 
-        if let Some(version_str) = self.read_node_version_file(directory) {
+        if let Some(request) = self.find_node_version_file(directory) {
+            debug!(
+                "Using Node.js version {} from {}",
+                request.version,
+                request.source.display()
+            );
+            let version_str = request.version;
+
             // Detect all installations
             let installations = self.detect_installations().await?;
 
-            // Find matching version (support both "v20" and "20" format)
-            let cleaned = version_str.trim_start_matches('v');
-
-            for installation in installations {
-                let inst_version = installation.version.to_string();
-                // Exact match or major version match
-                if inst_version == cleaned || inst_version.starts_with(&format!("{}.", cleaned)) {
-                    return Ok(Some(installation));
-                }
+            // Match the version file as a constraint ("v20", "^20.10", "lts/iron", ...)
+            let constraint = match VersionConstraint::parse(&version_str) {
+                Ok(c) => c,
+                Err(_) => return Ok(None),
+            };
+
+            let best = constraint
+                .best_match(installations.iter().map(|i| &i.version))
+                .cloned();
+            if let Some(best) = best {
+                return Ok(installations.into_iter().find(|i| i.version == best));
             }
         }
 
@@ -365,6 +363,38 @@ mod tests {
         assert_eq!(version, Some("20.10.0".to_string()));
     }
 
+    #[test]
+    fn test_read_node_version_file_from_parent() {
+        let detector = NodeJsDetector::new();
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("packages/app");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(temp_dir.path().join(".nvmrc"), "20.10.0\n").unwrap();
+
+        let request = detector.find_node_version_file(&nested).unwrap();
+        assert_eq!(request.version, "20.10.0");
+        assert_eq!(request.source, temp_dir.path().join(".nvmrc"));
+    }
+
+    #[test]
+    fn test_read_node_version_file_package_json() {
+        let detector = NodeJsDetector::new();
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"engines": {"node": "^20 || ^22"}}"#,
+        )
+        .unwrap();
+
+        let version = detector.read_node_version_file(temp_dir.path());
+        assert_eq!(version, Some("^20 || ^22".to_string()));
+
+        // .nvmrc in the same directory takes precedence
+        fs::write(temp_dir.path().join(".nvmrc"), "18.17.1\n").unwrap();
+        let version = detector.read_node_version_file(temp_dir.path());
+        assert_eq!(version, Some("18.17.1".to_string()));
+    }
+
     #[test]
     fn test_read_node_version_file_missing() {
         let detector = NodeJsDetector::new();
@@ -2,12 +2,14 @@ mod api;
 mod detector;
 mod installer;
 
+use crate::core::env::EnvAction;
 use crate::core::traits::{
-    Architecture, DetectedInstallation, InstalledTool, Platform, PluginCategory, PluginMetadata,
-    ToolDistribution, ToolInfo, ToolPlugin, ToolProvider, ToolVersion,
+    compare_tags, Architecture, DetectedInstallation, InstalledTool, Platform, PluginCategory,
+    PluginMetadata, ToolDistribution, ToolInfo, ToolPlugin, ToolProvider, ToolVersion,
 };
 use crate::error::Result;
 use async_trait::async_trait;
+use std::cmp::Ordering;
 use std::path::{Path, PathBuf};
 
 pub use api::NodeJsApi;
@@ -99,7 +101,17 @@ impl ToolProvider for NodeJsPlugin {
             _ => cleaned,
         };
 
-        let parts: Vec<&str> = resolved.split('.').collect();
+        // Split off pre-release and build tags (e.g. "23.0.0-rc.1", "22.0.0-nightly+abc")
+        let (core, build) = match resolved.split_once('+') {
+            Some((core, build)) => (core, Some(build)),
+            None => (resolved, None),
+        };
+        let (core, pre_release) = match core.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (core, None),
+        };
+
+        let parts: Vec<&str> = core.split('.').collect();
 
         let major = parts
             .first()
@@ -115,6 +127,14 @@ impl ToolProvider for NodeJsPlugin {
         let mut version =
             ToolVersion::new(resolved.to_string(), major, minor, patch).with_lts(is_lts);
 
+        if let Some(pre) = pre_release.filter(|p| !p.is_empty()) {
+            version = version.with_pre_release(pre.to_string());
+        }
+
+        if let Some(build) = build.filter(|b| !b.is_empty()) {
+            version = version.with_build(build.to_string());
+        }
+
         // Add LTS code name if available
         if is_lts {
             let lts_name = match major {
@@ -134,6 +154,13 @@ impl ToolProvider for NodeJsPlugin {
         Ok(version)
     }
 
+    fn compare_pre_release(&self, a: &str, b: &str) -> Ordering {
+        // Nightly and canary builds ("nightly20240101abc", "v8-canary2024...") come
+        // before a release's rcs
+        let is_dev = |tag: &str| tag.starts_with("nightly") || tag.starts_with("v8-canary");
+        is_dev(b).cmp(&is_dev(a)).then_with(|| compare_tags(a, b))
+    }
+
     fn validate_installation(&self, path: &Path) -> Result<bool> {
         Ok(path.join("bin/node").exists() || path.join("node.exe").exists())
     }
@@ -154,16 +181,16 @@ impl ToolProvider for NodeJsPlugin {
         }
     }
 
-    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<(String, String)>> {
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>> {
         let bin_path = if cfg!(windows) {
-            install_path.display().to_string()
+            install_path.to_path_buf()
         } else {
-            format!("{}/bin", install_path.display())
+            install_path.join("bin")
         };
 
         Ok(vec![
-            ("NODE_HOME".to_string(), install_path.display().to_string()),
-            ("PATH".to_string(), format!("{}:$PATH", bin_path)),
+            EnvAction::set("NODE_HOME", install_path.display().to_string()),
+            EnvAction::prepend_path("PATH", bin_path),
         ])
     }
 }
@@ -253,6 +280,24 @@ mod tests {
         assert!(version.is_lts);
     }
 
+    #[test]
+    fn test_parse_version_pre_release() {
+        let plugin = NodeJsPlugin::new();
+
+        let rc = plugin.parse_version("v23.0.0-rc.1").unwrap();
+        assert_eq!(rc.patch, Some(0));
+        assert_eq!(rc.pre_release.as_deref(), Some("rc.1"));
+        assert!(rc < plugin.parse_version("23.0.0").unwrap());
+        assert!(rc < plugin.parse_version("23.0.0-rc.2").unwrap());
+
+        let nightly = plugin.parse_version("v23.0.0-nightly20240901abc").unwrap();
+        assert_eq!(plugin.compare_versions(&nightly, &rc), Ordering::Less);
+        assert_eq!(
+            plugin.compare_versions(&nightly, &plugin.parse_version("22.9.0").unwrap()),
+            Ordering::Greater
+        );
+    }
+
     #[test]
     fn test_parse_version_non_lts() {
         let plugin = NodeJsPlugin::new();
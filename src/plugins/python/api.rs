@@ -138,12 +138,7 @@ impl PythonApi {
         }
 
         // Sort versions (newest first)
-        versions.sort_by(|a, b| {
-            b.major
-                .cmp(&a.major)
-                .then_with(|| b.minor.cmp(&a.minor))
-                .then_with(|| b.patch.cmp(&a.patch))
-        });
+        versions.sort_by(|a, b| b.cmp(a));
 
         Ok(versions)
     }
@@ -228,12 +223,7 @@ impl PythonApi {
         }
 
         // Sort versions (newest first)
-        versions.sort_by(|a, b| {
-            b.major
-                .cmp(&a.major)
-                .then_with(|| b.minor.cmp(&a.minor))
-                .then_with(|| b.patch.cmp(&a.patch))
-        });
+        versions.sort_by(|a, b| b.cmp(a));
 
         // Deduplicate
         versions.dedup_by(|a, b| a.to_string() == b.to_string());
@@ -1,8 +1,11 @@
+use crate::core::constraint::VersionConstraint;
 use crate::core::traits::{DetectedInstallation, InstalledTool, ToolDetector, ToolVersion};
 use crate::error::Result;
+use crate::project::VersionRequest;
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use tracing::debug;
 
 /// Python installation detector
 ///
@@ -296,39 +299,51 @@ impl PythonDetector {
         installations
     }
 
-    /// Reads .python-version file in the current directory
-    /// Returns the version string if found
+    /// Reads the nearest .python-version file (or pyproject.toml `requires-python`),
+    /// starting in the given directory and walking up to the filesystem root (or
+    /// `JCVM_PROJECT_ROOT`). Returns the version string if found
     pub fn read_python_version_file(&self, directory: &Path) -> Option<String> {
-        let version_file = directory.join(".python-version");
-        if !version_file.exists() {
-            return None;
-        }
+        self.find_python_version_file(directory).map(|r| r.version)
+    }
 
-        std::fs::read_to_string(&version_file)
+    /// Like `read_python_version_file`, but also returns the file that won
+    pub fn find_python_version_file(&self, directory: &Path) -> Option<VersionRequest> {
+        crate::project::find_version_file(directory, "python")
             .ok()
-            .map(|s| s.trim().to_string())
+            .flatten()
     }
 
-    /// Finds the installation matching a .python-version file
+    /// Finds the best installation matching a .python-version or pyproject.toml constraint
     pub async fn find_for_version_file(
         &self,
         directory: &Path,
     ) -> Result<Option<DetectedInstallation>> {
-        let version_str = match self.read_python_version_file(directory) {
-            Some(v) => v,
+        let version_str = match self.find_python_version_file(directory) {
+            Some(request) => {
+                debug!(
+                    "Using Python version {} from {}",
+                    request.version,
+                    request.source.display()
+                );
+                request.version
+            }
             None => return Ok(None),
         };
 
         // Detect all installations
         let all_installations = self.detect_installations().await?;
 
-        // Find matching version
-        for installation in all_installations {
-            if installation.version.to_string() == version_str
-                || installation.version.to_string().starts_with(&version_str)
-            {
-                return Ok(Some(installation));
-            }
+        // Match the version file as a constraint ("3.12", "~3.11", ">=3.10", ...)
+        let constraint = match VersionConstraint::parse(&version_str) {
+            Ok(c) => c,
+            Err(_) => return Ok(None),
+        };
+
+        let best = constraint
+            .best_match(all_installations.iter().map(|i| &i.version))
+            .cloned();
+        if let Some(best) = best {
+            return Ok(all_installations.into_iter().find(|i| i.version == best));
         }
 
         Ok(None)
@@ -506,6 +521,21 @@ mod tests {
         std::fs::remove_dir_all(&temp_dir).unwrap();
     }
 
+    #[test]
+    fn test_read_python_version_file_pyproject() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nrequires-python = \"~=3.11\"\n",
+        )
+        .unwrap();
+
+        let detector = PythonDetector::new(PathBuf::from("/tmp/jcvm"));
+        let version = detector.read_python_version_file(temp_dir.path());
+
+        assert_eq!(version, Some(">=3.11,<4".to_string()));
+    }
+
     #[test]
     fn test_read_python_version_file_missing() {
         let temp_dir = std::env::temp_dir().join("jcvm_test_no_version_file");
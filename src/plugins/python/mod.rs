@@ -2,6 +2,7 @@ mod api;
 mod detector;
 mod installer;
 
+use crate::core::env::EnvAction;
 use crate::core::traits::{
     Architecture, DetectedInstallation, InstalledTool, Platform, ToolDetector, ToolDistribution,
     ToolInfo, ToolInstaller, ToolPlugin, ToolProvider, ToolVersion,
@@ -95,7 +96,7 @@ impl ToolProvider for PythonPlugin {
     }
 
     fn parse_version(&self, version_str: &str) -> Result<ToolVersion> {
-        // Parse versions like "3.12.8", "3.11.0"
+        // Parse versions like "3.12.8", "3.11.0", "3.13.0rc1", "3.14.0a1"
         let parts: Vec<&str> = version_str.split('.').collect();
 
         let major = parts
@@ -104,14 +105,26 @@ impl ToolProvider for PythonPlugin {
             .ok_or_else(|| crate::error::JcvmError::InvalidVersion(version_str.to_string()))?;
 
         let minor = parts.get(1).and_then(|p| p.parse::<u32>().ok());
-        let patch = parts.get(2).and_then(|p| p.parse::<u32>().ok());
-
-        Ok(ToolVersion::new(
-            version_str.to_string(),
-            major,
-            minor,
-            patch,
-        ))
+
+        // PEP 440 pre-release tags are appended directly to the patch number
+        let (patch, pre_release) = match parts.get(2) {
+            Some(p) => {
+                let digits = p.find(|c: char| !c.is_ascii_digit()).unwrap_or(p.len());
+                let pre = &p[digits..];
+                (
+                    p[..digits].parse::<u32>().ok(),
+                    (!pre.is_empty()).then(|| pre.to_string()),
+                )
+            }
+            None => (None, None),
+        };
+
+        let mut version = ToolVersion::new(version_str.to_string(), major, minor, patch);
+        if let Some(pre) = pre_release {
+            version = version.with_pre_release(pre);
+        }
+
+        Ok(version)
     }
 
     fn validate_installation(&self, path: &Path) -> Result<bool> {
@@ -141,33 +154,27 @@ impl ToolProvider for PythonPlugin {
         }
     }
 
-    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<(String, String)>> {
+    fn get_environment_vars(&self, install_path: &Path) -> Result<Vec<EnvAction>> {
         let mut env_vars = Vec::new();
 
-        env_vars.push((
-            "PYTHON_HOME".to_string(),
+        env_vars.push(EnvAction::set(
+            "PYTHON_HOME",
             install_path.to_string_lossy().to_string(),
         ));
 
         if cfg!(windows) {
-            env_vars.push((
-                "PATH".to_string(),
-                format!(
-                    "{};{}\\Scripts",
-                    install_path.display(),
-                    install_path.display()
-                ),
+            env_vars.push(EnvAction::prepend_path(
+                "PATH",
+                install_path.join("Scripts"),
             ));
+            env_vars.push(EnvAction::prepend_path("PATH", install_path));
         } else {
-            env_vars.push((
-                "PATH".to_string(),
-                format!("{}/bin", install_path.display()),
-            ));
+            env_vars.push(EnvAction::prepend_path("PATH", install_path.join("bin")));
 
             // Set library paths for shared libraries
-            env_vars.push((
-                "LD_LIBRARY_PATH".to_string(),
-                format!("{}/lib", install_path.display()),
+            env_vars.push(EnvAction::prepend_path(
+                "LD_LIBRARY_PATH",
+                install_path.join("lib"),
             ));
         }
 
@@ -275,6 +282,12 @@ mod tests {
         assert_eq!(version.major, 3);
         assert_eq!(version.minor, Some(12));
         assert_eq!(version.patch, Some(8));
+        assert!(version.pre_release.is_none());
+
+        let rc = plugin.parse_version("3.13.0rc1").unwrap();
+        assert_eq!(rc.patch, Some(0));
+        assert_eq!(rc.pre_release.as_deref(), Some("rc1"));
+        assert!(rc < plugin.parse_version("3.13.0").unwrap());
     }
 
     #[test]
@@ -301,18 +314,15 @@ mod tests {
         let install_path = PathBuf::from("/opt/jcvm/python/3.12.8");
         let env_vars = plugin.get_environment_vars(&install_path).unwrap();
 
-        // Convert to HashMap for easier testing
-        let env_map: std::collections::HashMap<_, _> = env_vars.into_iter().collect();
-
         assert_eq!(
-            env_map.get("PYTHON_HOME").unwrap(),
-            "/opt/jcvm/python/3.12.8"
+            env_vars[0],
+            EnvAction::set("PYTHON_HOME", "/opt/jcvm/python/3.12.8")
         );
         if !cfg!(windows) {
-            assert!(env_map
-                .get("PATH")
-                .unwrap()
-                .contains("/opt/jcvm/python/3.12.8/bin"));
+            assert!(env_vars.contains(&EnvAction::prepend_path(
+                "PATH",
+                "/opt/jcvm/python/3.12.8/bin"
+            )));
         }
     }
 
@@ -0,0 +1,167 @@
+//! Java levels declared by Maven and Gradle builds, used when a project has no explicit
+//! Java version file. Only the feature release is inferred (`<java.version>17` → "17").
+
+use super::VersionRequest;
+use crate::error::Result;
+use std::fs;
+use std::path::Path;
+
+pub const POM_FILE: &str = "pom.xml";
+pub const GRADLE_PROPERTIES_FILE: &str = "gradle.properties";
+
+/// Build files in order of precedence
+pub const BUILD_FILES: &[&str] = &[
+    POM_FILE,
+    "build.gradle.kts",
+    "build.gradle",
+    GRADLE_PROPERTIES_FILE,
+];
+
+/// Maven properties naming the Java level, in order of precedence
+const POM_PROPERTIES: &[&str] = &[
+    "maven.compiler.release",
+    "java.version",
+    "maven.compiler.source",
+    "release",
+];
+
+/// Gradle settings naming the Java level, in order of precedence
+const GRADLE_KEYS: &[&str] = &[
+    "JavaLanguageVersion.of",
+    "jvmToolchain",
+    "sourceCompatibility",
+];
+
+/// Keys read from `gradle.properties`, in order of precedence
+const GRADLE_PROPERTIES: &[&str] = &["java.toolchain.languageVersion", "sourceCompatibility"];
+
+/// Java version inferred from the build files in `dir`, if any declares one
+pub fn load(dir: &Path) -> Result<Option<VersionRequest>> {
+    for name in BUILD_FILES {
+        let path = dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        let version = match *name {
+            POM_FILE => parse_pom(&contents),
+            GRADLE_PROPERTIES_FILE => parse_gradle_properties(&contents),
+            _ => parse_gradle(&contents),
+        };
+        if let Some(version) = version {
+            return Ok(Some(VersionRequest::new("java", version, &path)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Java level of a `pom.xml`, following one level of `${property}` references
+pub fn parse_pom(contents: &str) -> Option<String> {
+    POM_PROPERTIES.iter().find_map(|property| {
+        let value = xml_value(contents, property)?;
+        let value = match value.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+            Some(reference) => xml_value(contents, reference)?,
+            None => value,
+        };
+        java_major(value)
+    })
+}
+
+/// Java level of a `build.gradle` or `build.gradle.kts` file
+pub fn parse_gradle(contents: &str) -> Option<String> {
+    let lines: Vec<&str> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("//") && !line.starts_with('*'))
+        .collect();
+
+    GRADLE_KEYS.iter().find_map(|key| {
+        lines.iter().find_map(|line| {
+            let (_, rest) = line.split_once(key)?;
+            let rest = rest.trim_start();
+            let rest = rest
+                .strip_prefix('=')
+                .or_else(|| rest.strip_prefix(".set("))
+                .or_else(|| rest.strip_prefix('('))?;
+            let token = rest.split([')', ';', ',']).next()?;
+            java_major(token)
+        })
+    })
+}
+
+/// Java level of a `gradle.properties` file
+pub fn parse_gradle_properties(contents: &str) -> Option<String> {
+    GRADLE_PROPERTIES.iter().find_map(|key| {
+        contents.lines().find_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            (name.trim() == *key).then(|| java_major(value)).flatten()
+        })
+    })
+}
+
+/// Text of the first `<tag>` element
+fn xml_value<'a>(contents: &'a str, tag: &str) -> Option<&'a str> {
+    let start = contents.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = contents[start..].find(&format!("</{}>", tag))?;
+    Some(contents[start..start + end].trim())
+}
+
+/// Feature release of a Java level: "17", "1.8", "'11'", "JavaVersion.VERSION_1_8"
+fn java_major(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(['"', '\'']);
+    let value = value
+        .strip_prefix("JavaVersion.VERSION_")
+        .map(|v| v.replace('_', "."))
+        .or_else(|| {
+            value
+                .strip_prefix("JavaVersion.toVersion(")
+                .map(String::from)
+        })
+        .unwrap_or_else(|| value.to_string());
+    let value = value.trim_matches(['"', '\'']);
+    // Java 8 and earlier are written "1.8"
+    let value = value.strip_prefix("1.").unwrap_or(value);
+
+    let major = value.split('.').next()?;
+    (!major.is_empty() && major.chars().all(|c| c.is_ascii_digit())).then(|| major.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pom_properties() {
+        let spring =
+            "<project><properties>\n  <java.version>17</java.version>\n</properties></project>";
+        assert_eq!(parse_pom(spring), Some("17".to_string()));
+
+        let release = "<properties><maven.compiler.source>1.8</maven.compiler.source>\
+            <maven.compiler.release>${jdk}</maven.compiler.release><jdk>21</jdk></properties>";
+        assert_eq!(parse_pom(release), Some("21".to_string()));
+
+        assert_eq!(parse_pom("<project></project>"), None);
+    }
+
+    #[test]
+    fn parses_gradle_settings() {
+        let kotlin = "java {\n    toolchain {\n        languageVersion.set(JavaLanguageVersion.of(21))\n    }\n}\n";
+        assert_eq!(parse_gradle(kotlin), Some("21".to_string()));
+
+        let groovy = "// sourceCompatibility = '1.7'\njava {\n    sourceCompatibility = JavaVersion.VERSION_1_8\n}\n";
+        assert_eq!(parse_gradle(groovy), Some("8".to_string()));
+
+        assert_eq!(
+            parse_gradle("kotlin {\n    jvmToolchain(17)\n}\n"),
+            Some("17".to_string())
+        );
+        assert_eq!(parse_gradle("plugins { id 'java' }"), None);
+
+        assert_eq!(
+            parse_gradle_properties("org.gradle.jvmargs=-Xmx2g\nsourceCompatibility=11\n"),
+            Some("11".to_string())
+        );
+    }
+}
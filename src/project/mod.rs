@@ -0,0 +1,556 @@
+pub mod build_files;
+pub mod package_json;
+pub mod pyproject;
+pub mod sdkman;
+pub mod tasks;
+pub mod tool_versions;
+
+use crate::core::env::EnvAction;
+use crate::error::{JcvmError, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project manifest file name
+pub const PROJECT_FILE: &str = "jcvm.toml";
+
+/// Directory at which the upward search for project files stops
+pub const PROJECT_ROOT_ENV: &str = "JCVM_PROJECT_ROOT";
+
+/// Variable holding the `jcvm shell` override of a tool, e.g. `JCVM_JAVA_VERSION`
+pub fn session_env_var(tool_id: &str) -> String {
+    format!("JCVM_{}_VERSION", tool_id.to_uppercase().replace('-', "_"))
+}
+
+/// Version selected for this shell session with `jcvm shell`. It takes precedence over
+/// project files; its source is the variable name.
+pub fn session_version(tool_id: &str) -> Option<VersionRequest> {
+    let var = session_env_var(tool_id);
+    let version = std::env::var(&var).ok()?;
+    let version = version.trim();
+    (!version.is_empty()).then(|| VersionRequest::new(tool_id, version, Path::new(&var)))
+}
+
+/// Files whose presence means a directory requests tool versions
+pub const MARKER_FILES: &[&str] = &[
+    PROJECT_FILE,
+    tool_versions::TOOL_VERSIONS_FILE,
+    sdkman::SDKMANRC_FILE,
+    ".java-version",
+    ".nvmrc",
+    ".node-version",
+    ".python-version",
+    package_json::PACKAGE_JSON_FILE,
+    pyproject::PYPROJECT_FILE,
+    build_files::POM_FILE,
+    "build.gradle.kts",
+    "build.gradle",
+    build_files::GRADLE_PROPERTIES_FILE,
+];
+
+/// A `jcvm.toml` project manifest.
+///
+/// ```toml
+/// [tools]
+/// java = "21"
+/// node = "lts"
+/// python = "~3.12"
+///
+/// [env]
+/// MAVEN_OPTS = "-Xmx2g"
+/// APP_CONFIG = "{project_root}/config"
+///
+/// [settings]
+/// install_on_enter = true
+///
+/// [tasks]
+/// test = "mvn -q test"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Version or constraint per tool ID
+    #[serde(default)]
+    pub tools: BTreeMap<String, String>,
+
+    /// Environment variables for the project; values may use `{project_root}`
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+
+    #[serde(default)]
+    pub settings: ProjectSettings,
+
+    /// Named commands for `jcvm run`
+    #[serde(default)]
+    pub tasks: BTreeMap<String, tasks::Task>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectSettings {
+    /// Install missing tool versions when entering the project
+    #[serde(default)]
+    pub install_on_enter: bool,
+}
+
+/// A loaded project manifest and the directory it lives in
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub root: PathBuf,
+    pub config: ProjectConfig,
+}
+
+/// A tool version requested by a project and the file that requested it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRequest {
+    pub tool_id: String,
+    pub version: String,
+    /// Versions to use instead when `version` is not installed, in order
+    pub fallbacks: Vec<String>,
+    pub source: PathBuf,
+}
+
+impl VersionRequest {
+    pub fn new(tool_id: impl Into<String>, version: impl Into<String>, source: &Path) -> Self {
+        Self {
+            tool_id: tool_id.into(),
+            version: version.into(),
+            fallbacks: Vec::new(),
+            source: source.to_path_buf(),
+        }
+    }
+
+    pub fn with_fallbacks(mut self, fallbacks: Vec<String>) -> Self {
+        self.fallbacks = fallbacks;
+        self
+    }
+
+    /// The requested version followed by its fallbacks
+    pub fn candidates(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.version.as_str()).chain(self.fallbacks.iter().map(String::as_str))
+    }
+
+    /// The source for messages: its file name when it is in the current directory
+    /// ("jcvm.toml"), otherwise its full path
+    pub fn source_name(&self) -> String {
+        let in_current_dir = std::env::current_dir()
+            .ok()
+            .is_some_and(|cwd| self.source.parent() == Some(cwd.as_path()));
+        match self.source.file_name() {
+            Some(name) if in_current_dir => name.to_string_lossy().to_string(),
+            _ => self.source.display().to_string(),
+        }
+    }
+}
+
+impl Project {
+    /// Load `jcvm.toml` from `dir`, if there is one
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(PROJECT_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        let config: ProjectConfig = toml::from_str(&contents)
+            .map_err(|e| JcvmError::ConfigError(format!("Invalid {}: {}", path.display(), e)))?;
+
+        Ok(Some(Self {
+            root: dir.to_path_buf(),
+            config,
+        }))
+    }
+
+    /// Load the nearest `jcvm.toml` in `dir` or its parents
+    pub fn find(dir: &Path) -> Result<Option<Self>> {
+        for dir in search_path(dir) {
+            if let Some(project) = Self::load(&dir)? {
+                return Ok(Some(project));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.root.join(PROJECT_FILE)
+    }
+
+    /// Environment actions for the project's `[env]` table
+    pub fn env(&self) -> Vec<EnvAction> {
+        let root = self.root.display().to_string();
+        self.config
+            .env
+            .iter()
+            .map(|(key, value)| EnvAction::set(key, value.replace("{project_root}", &root)))
+            .collect()
+    }
+
+    /// Set a tool version in the `jcvm.toml` in `dir`, leaving the rest of the file (comments,
+    /// blank lines, key order) as it is
+    pub fn write_tool_version(dir: &Path, tool_id: &str, version: &str) -> Result<()> {
+        let path = dir.join(PROJECT_FILE);
+        let contents = if path.is_file() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        let invalid = |e: &dyn std::fmt::Display| {
+            JcvmError::ConfigError(format!("Invalid {}: {}", path.display(), e))
+        };
+
+        let mut document: toml_edit::DocumentMut = contents.parse().map_err(|e| invalid(&e))?;
+        let tools = document
+            .entry("tools")
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .ok_or_else(|| {
+                JcvmError::ConfigError(format!("[tools] in {} is not a table", path.display()))
+            })?;
+        match tools.get_mut(tool_id).and_then(|item| item.as_value_mut()) {
+            // Keep the comments and spacing around the existing value
+            Some(value) => {
+                let decor = value.decor().clone();
+                *value = version.into();
+                *value.decor_mut() = decor;
+            }
+            None => {
+                tools.insert(tool_id, toml_edit::value(version));
+            }
+        }
+
+        fs::write(&path, document.to_string())?;
+        Ok(())
+    }
+}
+
+/// Requests from the multi-tool manifests in `dir`, in order of precedence:
+/// `jcvm.toml`, `.tool-versions`, then `.sdkmanrc`
+fn manifest_requests(dir: &Path) -> Result<Vec<VersionRequest>> {
+    let mut requests = Vec::new();
+
+    if let Some(project) = Project::load(dir)? {
+        let path = project.path();
+        requests.extend(
+            project
+                .config
+                .tools
+                .iter()
+                .map(|(tool_id, version)| VersionRequest::new(tool_id, version, &path)),
+        );
+    }
+    requests.extend(tool_versions::load(dir)?);
+    requests.extend(sdkman::load(dir)?);
+
+    Ok(requests)
+}
+
+/// `dir` followed by its parents, up to the filesystem root or the `JCVM_PROJECT_ROOT`
+/// directory when `dir` is inside it
+pub fn search_path(dir: &Path) -> Vec<PathBuf> {
+    let boundary = std::env::var_os(PROJECT_ROOT_ENV)
+        .filter(|root| !root.is_empty())
+        .map(PathBuf::from);
+    search_path_within(dir, boundary.as_deref())
+}
+
+fn search_path_within(dir: &Path, boundary: Option<&Path>) -> Vec<PathBuf> {
+    let boundary = boundary.filter(|root| dir.starts_with(root));
+    let mut dirs = Vec::new();
+    for ancestor in dir.ancestors() {
+        dirs.push(ancestor.to_path_buf());
+        if boundary == Some(ancestor) {
+            break;
+        }
+    }
+    dirs
+}
+
+/// Requests declared in `dir` itself: manifests first, then version files of `tool_ids`
+fn requests_in(dir: &Path, tool_ids: &[String]) -> Result<Vec<VersionRequest>> {
+    let mut requests: Vec<VersionRequest> = Vec::new();
+
+    for request in manifest_requests(dir)? {
+        if !requests.iter().any(|r| r.tool_id == request.tool_id) {
+            requests.push(request);
+        }
+    }
+
+    for tool_id in tool_ids {
+        if requests.iter().any(|r| &r.tool_id == tool_id) {
+            continue;
+        }
+        if let Some(request) = read_version_file(dir, tool_id)? {
+            requests.push(request);
+        }
+    }
+
+    Ok(requests)
+}
+
+/// Version of `tool_id` requested for `dir`. The nearest directory that declares the tool
+/// wins; within a directory the multi-tool manifests come before version files. Java falls
+/// back to the level declared by Maven or Gradle build files.
+pub fn resolve_version(dir: &Path, tool_id: &str) -> Result<Option<VersionRequest>> {
+    let tool_ids = [tool_id.to_string()];
+    for dir in search_path(dir) {
+        if let Some(request) = requests_in(&dir, &tool_ids)?
+            .into_iter()
+            .find(|r| r.tool_id == tool_id)
+        {
+            return Ok(Some(request));
+        }
+    }
+
+    if tool_id == "java" {
+        return infer_java_version(dir);
+    }
+    Ok(None)
+}
+
+/// Every tool version requested for `dir`, checking version files for the given tools
+/// that no manifest declares. Each tool comes from the nearest directory declaring it.
+/// Manifest entries for tools other than `tool_ids` are left out.
+pub fn resolve_all(dir: &Path, tool_ids: &[String]) -> Result<Vec<VersionRequest>> {
+    Ok(resolve_declared(dir, tool_ids)?
+        .into_iter()
+        .filter(|r| tool_ids.contains(&r.tool_id))
+        .collect())
+}
+
+/// Manifest entries for `dir` naming tools other than `tool_ids`, e.g. `maven` in `.sdkmanrc`
+pub fn unsupported_requests(dir: &Path, tool_ids: &[String]) -> Result<Vec<VersionRequest>> {
+    Ok(resolve_declared(dir, tool_ids)?
+        .into_iter()
+        .filter(|r| !tool_ids.contains(&r.tool_id))
+        .collect())
+}
+
+fn resolve_declared(dir: &Path, tool_ids: &[String]) -> Result<Vec<VersionRequest>> {
+    let mut requests: Vec<VersionRequest> = Vec::new();
+
+    for dir in search_path(dir) {
+        for request in requests_in(&dir, tool_ids)? {
+            if !requests.iter().any(|r| r.tool_id == request.tool_id) {
+                requests.push(request);
+            }
+        }
+    }
+
+    let wants_java = tool_ids.iter().any(|t| t == "java");
+    if wants_java && !requests.iter().any(|r| r.tool_id == "java") {
+        requests.extend(infer_java_version(dir)?);
+    }
+
+    Ok(requests)
+}
+
+/// Java version from the nearest Maven or Gradle build that declares one. Explicit version
+/// files anywhere up the tree take precedence over this.
+pub fn infer_java_version(dir: &Path) -> Result<Option<VersionRequest>> {
+    for dir in search_path(dir) {
+        if let Some(request) = build_files::load(&dir)? {
+            return Ok(Some(request));
+        }
+    }
+    Ok(None)
+}
+
+/// Nearest version file of `tool_id` (e.g. `.java-version`, `.nvmrc`, `pyproject.toml`) in
+/// `dir` or its parents, ignoring the multi-tool manifests
+pub fn find_version_file(dir: &Path, tool_id: &str) -> Result<Option<VersionRequest>> {
+    for dir in search_path(dir) {
+        if let Some(request) = read_version_file(&dir, tool_id)? {
+            return Ok(Some(request));
+        }
+    }
+    Ok(None)
+}
+
+/// Version file names of a tool, in order of precedence
+fn version_file_names(tool_id: &str) -> Vec<String> {
+    match tool_id {
+        "node" => vec![".nvmrc".to_string(), ".node-version".to_string()],
+        _ => vec![format!(".{}-version", tool_id)],
+    }
+}
+
+/// Version file of `tool_id` in `dir`; Node falls back to `package.json` and Python to
+/// `pyproject.toml`
+fn read_version_file(dir: &Path, tool_id: &str) -> Result<Option<VersionRequest>> {
+    for name in version_file_names(tool_id) {
+        let path = dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        let version = contents.trim();
+        if !version.is_empty() {
+            return Ok(Some(VersionRequest::new(tool_id, version, &path)));
+        }
+    }
+
+    match tool_id {
+        "node" => package_json::load(dir),
+        "python" => pyproject::load(dir),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn session_override_is_named_after_the_tool() {
+        assert_eq!(session_env_var("java"), "JCVM_JAVA_VERSION");
+        assert_eq!(session_env_var("graal-vm"), "JCVM_GRAAL_VM_VERSION");
+
+        let request = VersionRequest::new("java", "17", Path::new("JCVM_JAVA_VERSION"));
+        assert_eq!(request.source_name(), "JCVM_JAVA_VERSION");
+    }
+
+    #[test]
+    fn project_file_takes_precedence_over_version_files() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(PROJECT_FILE),
+            "[tools]\njava = \"21\"\nnode = \"lts\"\n\n[env]\nAPP_HOME = \"{project_root}/app\"\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join(".java-version"), "17\n").unwrap();
+        fs::write(dir.path().join(".python-version"), "3.12\n").unwrap();
+        fs::write(
+            dir.path().join(tool_versions::TOOL_VERSIONS_FILE),
+            "java 11\nnodejs 20.11.0 18.19.0\nruby 3.3.0\n",
+        )
+        .unwrap();
+
+        let java = resolve_version(dir.path(), "java").unwrap().unwrap();
+        assert_eq!(java.version, "21");
+        assert_eq!(java.source, dir.path().join(PROJECT_FILE));
+
+        let tools = ["java", "node", "python", "ruby"].map(String::from);
+        let all = resolve_all(dir.path(), &tools).unwrap();
+        let versions: Vec<_> = all
+            .iter()
+            .map(|r| (r.tool_id.as_str(), r.version.as_str()))
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                ("java", "21"),
+                ("node", "lts"),
+                ("ruby", "3.3.0"),
+                ("python", "3.12")
+            ]
+        );
+
+        let project = Project::load(dir.path()).unwrap().unwrap();
+        assert_eq!(
+            project.env(),
+            vec![EnvAction::set(
+                "APP_HOME",
+                format!("{}/app", dir.path().display())
+            )]
+        );
+    }
+
+    #[test]
+    fn manifest_entries_without_a_plugin_are_left_out() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(".sdkmanrc"),
+            "java=21.0.2-tem\nmaven=3.9.6\n",
+        )
+        .unwrap();
+
+        let tools = ["java", "node"].map(String::from);
+        let all = resolve_all(dir.path(), &tools).unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].tool_id, "java");
+
+        let unsupported = unsupported_requests(dir.path(), &tools).unwrap();
+        assert_eq!(unsupported.len(), 1);
+        assert_eq!(unsupported[0].tool_id, "maven");
+        assert_eq!(unsupported[0].version, "3.9.6");
+    }
+
+    #[test]
+    fn nearest_directory_wins_within_project_root() {
+        let root = TempDir::new().unwrap();
+        let nested = root.path().join("service/src/main");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.path().join(".java-version"), "17\n").unwrap();
+        fs::write(root.path().join("service/.nvmrc"), "20\n").unwrap();
+        fs::write(root.path().join("service/.java-version"), "21\n").unwrap();
+
+        let tools = ["java", "node"].map(String::from);
+        let all = resolve_all(&nested, &tools).unwrap();
+        assert_eq!(all[0].version, "21");
+        assert_eq!(all[0].source, root.path().join("service/.java-version"));
+        assert_eq!(all[1].source, root.path().join("service/.nvmrc"));
+
+        let within = search_path_within(&nested, Some(&root.path().join("service")));
+        assert_eq!(within.last().unwrap(), &root.path().join("service"));
+        // A boundary the directory is not inside of is ignored
+        let outside = search_path_within(&nested, Some(Path::new("/elsewhere")));
+        assert_eq!(outside.last().unwrap(), Path::new("/"));
+    }
+
+    #[test]
+    fn build_files_are_used_without_java_version_files() {
+        let root = TempDir::new().unwrap();
+        let module = root.path().join("api");
+        fs::create_dir_all(&module).unwrap();
+        fs::write(
+            root.path().join("pom.xml"),
+            "<properties><java.version>17</java.version></properties>",
+        )
+        .unwrap();
+        fs::write(module.join("build.gradle"), "sourceCompatibility = '11'\n").unwrap();
+
+        let java = resolve_version(&module, "java").unwrap().unwrap();
+        assert_eq!(java.version, "11");
+        assert_eq!(java.source, module.join("build.gradle"));
+
+        // An explicit version file anywhere up the tree wins over build files
+        fs::write(root.path().join(".java-version"), "21\n").unwrap();
+        let java = resolve_version(&module, "java").unwrap().unwrap();
+        assert_eq!(java.version, "21");
+    }
+
+    #[test]
+    fn write_tool_version_keeps_other_tables() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(PROJECT_FILE),
+            "[tools]\njava = \"17\"\n\n[settings]\ninstall_on_enter = true\n",
+        )
+        .unwrap();
+
+        Project::write_tool_version(dir.path(), "java", "21").unwrap();
+
+        let project = Project::load(dir.path()).unwrap().unwrap();
+        assert_eq!(project.config.tools["java"], "21");
+        assert!(project.config.settings.install_on_enter);
+    }
+
+    #[test]
+    fn write_tool_version_keeps_comments_and_layout() {
+        let dir = TempDir::new().unwrap();
+        let original = "# Team toolchain\n[env]\nMAVEN_OPTS = \"-Xmx2g\"  # CI needs more\n\n[tools]\nnode = \"20\"\njava = \"17\" # LTS only\n";
+        fs::write(dir.path().join(PROJECT_FILE), original).unwrap();
+
+        Project::write_tool_version(dir.path(), "java", "21").unwrap();
+        let updated = fs::read_to_string(dir.path().join(PROJECT_FILE)).unwrap();
+        assert_eq!(updated, original.replace("\"17\"", "\"21\""));
+
+        Project::write_tool_version(dir.path(), "python", "3.12").unwrap();
+        let updated = fs::read_to_string(dir.path().join(PROJECT_FILE)).unwrap();
+        assert!(updated.starts_with(&original.replace("\"17\"", "\"21\"")));
+        assert!(updated.ends_with("python = \"3.12\"\n"));
+    }
+}
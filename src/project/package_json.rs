@@ -0,0 +1,103 @@
+//! npm `package.json` files: the Volta pin (`"volta": {"node": "20.11.0"}`) and the
+//! `engines.node` range (`">=18 <21"`).
+
+use super::VersionRequest;
+use crate::error::Result;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use tracing::debug;
+
+pub const PACKAGE_JSON_FILE: &str = "package.json";
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageJson {
+    #[serde(default)]
+    volta: NodePin,
+    #[serde(default)]
+    engines: NodePin,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NodePin {
+    node: Option<String>,
+}
+
+fn read(dir: &Path) -> Result<Option<PackageJson>> {
+    let path = dir.join(PACKAGE_JSON_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&path)?;
+    // package.json belongs to npm; fields jcvm doesn't understand are not its error to report
+    match serde_json::from_str(&contents) {
+        Ok(package) => Ok(Some(package)),
+        Err(e) => {
+            debug!("Ignoring {}: {}", path.display(), e);
+            Ok(None)
+        }
+    }
+}
+
+/// Node version requested by `dir/package.json`: the Volta pin, else the `engines` range
+pub fn load(dir: &Path) -> Result<Option<VersionRequest>> {
+    let Some(package) = read(dir)? else {
+        return Ok(None);
+    };
+
+    let path = dir.join(PACKAGE_JSON_FILE);
+    Ok(package
+        .volta
+        .node
+        .or(package.engines.node)
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| VersionRequest::new("node", v.trim(), &path)))
+}
+
+/// Nearest `engines.node` range in `dir` or its parents
+pub fn find_engines(dir: &Path) -> Result<Option<VersionRequest>> {
+    for dir in super::search_path(dir) {
+        let range = read(&dir)?.and_then(|p| p.engines.node);
+        if let Some(range) = range.filter(|r| !r.trim().is_empty()) {
+            return Ok(Some(VersionRequest::new(
+                "node",
+                range.trim(),
+                &dir.join(PACKAGE_JSON_FILE),
+            )));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn volta_pin_takes_precedence_over_engines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(PACKAGE_JSON_FILE);
+
+        fs::write(
+            &path,
+            r#"{"name": "web", "engines": {"node": ">=18 <21", "npm": ">=9"}}"#,
+        )
+        .unwrap();
+        assert_eq!(load(dir.path()).unwrap().unwrap().version, ">=18 <21");
+
+        fs::write(
+            &path,
+            r#"{"engines": {"node": ">=18"}, "volta": {"node": "20.11.0", "npm": "10.2.4"}}"#,
+        )
+        .unwrap();
+        assert_eq!(load(dir.path()).unwrap().unwrap().version, "20.11.0");
+        assert_eq!(find_engines(dir.path()).unwrap().unwrap().version, ">=18");
+
+        fs::write(&path, r#"{"name": "no-node"}"#).unwrap();
+        assert_eq!(load(dir.path()).unwrap(), None);
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(load(dir.path()).unwrap(), None);
+    }
+}
@@ -0,0 +1,154 @@
+//! `pyproject.toml` files: `[project] requires-python` (PEP 621) and Poetry's
+//! `[tool.poetry.dependencies] python`, translated into jcvm constraints.
+
+use super::VersionRequest;
+use crate::error::Result;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use tracing::debug;
+
+pub const PYPROJECT_FILE: &str = "pyproject.toml";
+
+#[derive(Debug, Default, Deserialize)]
+struct PyProject {
+    #[serde(default)]
+    project: ProjectTable,
+    #[serde(default)]
+    tool: ToolTable,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectTable {
+    #[serde(rename = "requires-python")]
+    requires_python: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ToolTable {
+    #[serde(default)]
+    poetry: PoetryTable,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PoetryTable {
+    #[serde(default)]
+    dependencies: PoetryDependencies,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PoetryDependencies {
+    python: Option<String>,
+}
+
+/// Python constraint requested by `dir/pyproject.toml`, if it declares one
+pub fn load(dir: &Path) -> Result<Option<VersionRequest>> {
+    let path = dir.join(PYPROJECT_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&path)?;
+    let pyproject: PyProject = match toml::from_str(&contents) {
+        Ok(pyproject) => pyproject,
+        Err(e) => {
+            debug!("Ignoring {}: {}", path.display(), e);
+            return Ok(None);
+        }
+    };
+
+    Ok(pyproject
+        .project
+        .requires_python
+        .or(pyproject.tool.poetry.dependencies.python)
+        .and_then(|spec| to_constraint(&spec))
+        .map(|constraint| VersionRequest::new("python", constraint, &path)))
+}
+
+/// Translate a PEP 440 specifier set (`>=3.10,<3.13`, `~=3.11`, `==3.12.*`) into a jcvm
+/// constraint. Poetry's `^3.11` and `~3.11` already are one. Exclusions (`!=3.12.0`) can't
+/// be expressed and are dropped.
+pub fn to_constraint(spec: &str) -> Option<String> {
+    let clauses: Vec<String> = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|clause| !clause.is_empty() && !clause.starts_with("!="))
+        .map(|clause| {
+            if let Some(version) = clause.strip_prefix("~=") {
+                compatible_release(version.trim())
+            } else if let Some(version) = clause
+                .strip_prefix("===")
+                .or_else(|| clause.strip_prefix("=="))
+            {
+                format!("={}", version.trim())
+            } else {
+                clause.to_string()
+            }
+        })
+        .collect();
+
+    (!clauses.is_empty()).then(|| clauses.join(","))
+}
+
+/// `~=3.11` allows any 3.x from 3.11 on; `~=3.11.2` any 3.11.x from 3.11.2 on
+fn compatible_release(version: &str) -> String {
+    let parts: Vec<&str> = version.split('.').collect();
+    match parts.as_slice() {
+        [major, _] => match major.parse::<u32>() {
+            Ok(major) => format!(">={},<{}", version, major + 1),
+            Err(_) => format!(">={}", version),
+        },
+        _ => format!("~{}", version),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constraint::VersionConstraint;
+    use tempfile::TempDir;
+
+    #[test]
+    fn translates_pep440_specifiers() {
+        for (spec, constraint) in [
+            (">=3.10", ">=3.10"),
+            (">=3.10, <3.13", ">=3.10,<3.13"),
+            ("~=3.11", ">=3.11,<4"),
+            ("~=3.11.2", "~3.11.2"),
+            ("==3.12.*", "=3.12.*"),
+            (">=3.9,!=3.9.1", ">=3.9"),
+            ("^3.11", "^3.11"),
+        ] {
+            assert_eq!(to_constraint(spec).as_deref(), Some(constraint), "{}", spec);
+            assert!(
+                VersionConstraint::parse(constraint).is_ok(),
+                "{}",
+                constraint
+            );
+        }
+        assert_eq!(to_constraint("!=3.8.*"), None);
+    }
+
+    #[test]
+    fn requires_python_takes_precedence_over_poetry() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(PYPROJECT_FILE);
+
+        fs::write(
+            &path,
+            "[tool.poetry.dependencies]\npython = \"^3.11\"\nrequests = { version = \"^2.31\" }\n",
+        )
+        .unwrap();
+        assert_eq!(load(dir.path()).unwrap().unwrap().version, "^3.11");
+
+        fs::write(
+            &path,
+            "[project]\nname = \"svc\"\nrequires-python = \">=3.10\"\n\n[tool.poetry.dependencies]\npython = \"^3.11\"\n",
+        )
+        .unwrap();
+        assert_eq!(load(dir.path()).unwrap().unwrap().version, ">=3.10");
+
+        fs::write(&path, "[build-system]\nrequires = [\"hatchling\"]\n").unwrap();
+        assert_eq!(load(dir.path()).unwrap(), None);
+    }
+}
@@ -0,0 +1,104 @@
+//! SDKMAN `.sdkmanrc` files: `candidate=version` lines such as `java=21.0.2-tem`.
+
+use super::VersionRequest;
+use crate::error::Result;
+use std::fs;
+use std::path::Path;
+
+/// File name used by SDKMAN
+pub const SDKMANRC_FILE: &str = ".sdkmanrc";
+
+/// Read the requests in `dir/.sdkmanrc`, if the file exists
+pub fn load(dir: &Path) -> Result<Vec<VersionRequest>> {
+    let path = dir.join(SDKMANRC_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(&path)?;
+    Ok(parse(&contents)
+        .into_iter()
+        .map(|(tool_id, version)| VersionRequest::new(tool_id, version, &path))
+        .collect())
+}
+
+/// Parse `.sdkmanrc` contents into jcvm tool IDs and version requests
+pub fn parse(contents: &str) -> Vec<(String, String)> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let (candidate, version) = line.split_once('=')?;
+            let (candidate, version) = (candidate.trim().to_lowercase(), version.trim());
+            if candidate.is_empty() || version.is_empty() {
+                return None;
+            }
+            let version = match candidate.as_str() {
+                "java" => map_java_version(version),
+                _ => version.to_string(),
+            };
+            Some((candidate, version))
+        })
+        .collect()
+}
+
+/// Translate SDKMAN Java identifiers ("21.0.2-tem", "21.0.2.fx-zulu") into jcvm
+/// requests ("temurin-21.0.2", "zulu-21.0.2-jdkfx")
+fn map_java_version(identifier: &str) -> String {
+    let Some((version, suffix)) = identifier.rsplit_once('-') else {
+        return identifier.to_string();
+    };
+    let vendor = match suffix {
+        "tem" => "temurin",
+        "amzn" => "corretto",
+        "zulu" => "zulu",
+        "librca" => "liberica",
+        "sem" => "semeru",
+        "ms" => "microsoft",
+        "oracle" => "oracle",
+        // Oracle GraalVM and GraalVM CE share a release line; jcvm installs Community builds
+        "graal" | "graalce" => "graalvm",
+        // Plain OpenJDK builds ("22.ea.27-open") install from the default vendor
+        "open" => {
+            return match version.split_once(".ea.") {
+                Some((major, build)) => format!("{}-ea+{}", major, build),
+                None => version.to_string(),
+            }
+        }
+        _ => return identifier.to_string(),
+    };
+
+    match version.strip_suffix(".fx") {
+        Some(version) => format!("{}-{}-jdkfx", vendor, version),
+        None => format!("{}-{}", vendor, version),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_candidates_and_maps_java_vendors() {
+        let contents = "# Enable auto-env through the sdkman_auto_env config\njava=21.0.2-tem\nmaven=3.9.6\n\ngradle = 8.5 # build\n";
+        assert_eq!(
+            parse(contents),
+            vec![
+                ("java".to_string(), "temurin-21.0.2".to_string()),
+                ("maven".to_string(), "3.9.6".to_string()),
+                ("gradle".to_string(), "8.5".to_string()),
+            ]
+        );
+
+        for (sdkman, jcvm) in [
+            ("17.0.10-amzn", "corretto-17.0.10"),
+            ("21.0.2.fx-zulu", "zulu-21.0.2-jdkfx"),
+            ("21.0.2-graalce", "graalvm-21.0.2"),
+            ("21.0.2-graal", "graalvm-21.0.2"),
+            ("22.ea.27-open", "22-ea+27"),
+            ("21.0.2-unknown", "21.0.2-unknown"),
+        ] {
+            assert_eq!(map_java_version(sdkman), jcvm, "{}", sdkman);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! `[tasks]` in `jcvm.toml`: named commands run by `jcvm run` with the project's tools.
+//!
+//! ```toml
+//! [tasks]
+//! test = "mvn -q test"
+//!
+//! [tasks.web]
+//! run = "npm run build"
+//! tools = { node = "20" }
+//! env = { NODE_ENV = "production" }
+//!
+//! [tasks.ci]
+//! depends = ["test", "web"]
+//! ```
+
+use crate::core::env::EnvAction;
+use crate::error::{JcvmError, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A task: a command line, or a table describing one
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(from = "TaskSpec")]
+pub struct Task {
+    /// Command line, run through the system shell; tasks without one only group `depends`
+    pub run: Option<String>,
+
+    /// Tasks that must finish first
+    pub depends: Vec<String>,
+
+    /// Versions overriding the project's for this task
+    pub tools: BTreeMap<String, String>,
+
+    /// Environment variables for this task; values may use `{project_root}`
+    pub env: BTreeMap<String, String>,
+
+    /// Working directory, relative to the project root
+    pub dir: Option<String>,
+
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TaskSpec {
+    Command(String),
+    Table(TaskTable),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskTable {
+    run: Option<String>,
+    #[serde(default)]
+    depends: Vec<String>,
+    #[serde(default)]
+    tools: BTreeMap<String, String>,
+    #[serde(default)]
+    env: BTreeMap<String, String>,
+    dir: Option<String>,
+    description: Option<String>,
+}
+
+impl From<TaskSpec> for Task {
+    fn from(spec: TaskSpec) -> Self {
+        match spec {
+            TaskSpec::Command(run) => Task {
+                run: Some(run),
+                ..Task::default()
+            },
+            TaskSpec::Table(table) => Task {
+                run: table.run,
+                depends: table.depends,
+                tools: table.tools,
+                env: table.env,
+                dir: table.dir,
+                description: table.description,
+            },
+        }
+    }
+}
+
+impl Task {
+    /// Environment actions for the task's `env` table
+    pub fn env(&self, root: &Path) -> Vec<EnvAction> {
+        let root = root.display().to_string();
+        self.env
+            .iter()
+            .map(|(key, value)| EnvAction::set(key, value.replace("{project_root}", &root)))
+            .collect()
+    }
+}
+
+/// Order in which to run `targets` and their dependencies: each stage only depends on
+/// earlier ones, so the tasks within a stage can run in parallel
+pub fn plan(tasks: &BTreeMap<String, Task>, targets: &[String]) -> Result<Vec<Vec<String>>> {
+    let mut depth: BTreeMap<String, usize> = BTreeMap::new();
+    for target in targets {
+        stage_of(tasks, target, &mut depth, &mut Vec::new())?;
+    }
+
+    let mut stages: Vec<Vec<String>> = Vec::new();
+    for (name, stage) in depth {
+        if stages.len() <= stage {
+            stages.resize(stage + 1, Vec::new());
+        }
+        stages[stage].push(name);
+    }
+    Ok(stages)
+}
+
+/// Stage of a task: one after its deepest dependency
+fn stage_of(
+    tasks: &BTreeMap<String, Task>,
+    name: &str,
+    depth: &mut BTreeMap<String, usize>,
+    path: &mut Vec<String>,
+) -> Result<usize> {
+    if let Some(&stage) = depth.get(name) {
+        return Ok(stage);
+    }
+    if path.iter().any(|n| n == name) {
+        path.push(name.to_string());
+        return Err(JcvmError::ConfigError(format!(
+            "Task dependency cycle: {}",
+            path.join(" -> ")
+        )));
+    }
+    let task = tasks.get(name).ok_or_else(|| {
+        let defined: Vec<&str> = tasks.keys().map(String::as_str).collect();
+        JcvmError::ConfigError(format!(
+            "Unknown task '{}' (defined: {})",
+            name,
+            defined.join(", ")
+        ))
+    })?;
+
+    path.push(name.to_string());
+    let mut stage = 0;
+    for dependency in &task.depends {
+        stage = stage.max(stage_of(tasks, dependency, depth, path)? + 1);
+    }
+    path.pop();
+
+    depth.insert(name.to_string(), stage);
+    Ok(stage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tasks(toml: &str) -> BTreeMap<String, Task> {
+        #[derive(Deserialize)]
+        struct Manifest {
+            tasks: BTreeMap<String, Task>,
+        }
+        toml::from_str::<Manifest>(toml).unwrap().tasks
+    }
+
+    #[test]
+    fn parses_commands_and_tables() {
+        let tasks = tasks(
+            r#"
+[tasks]
+test = "mvn -q test"
+
+[tasks.web]
+run = "npm run build"
+tools = { node = "20" }
+env = { OUT = "{project_root}/dist" }
+"#,
+        );
+        assert_eq!(tasks["test"].run.as_deref(), Some("mvn -q test"));
+        assert_eq!(tasks["web"].tools["node"], "20");
+        assert_eq!(
+            tasks["web"].env(Path::new("/work/app")),
+            vec![EnvAction::set("OUT", "/work/app/dist")]
+        );
+    }
+
+    #[test]
+    fn plans_dependencies_in_parallel_stages() {
+        let tasks = tasks(
+            r#"
+[tasks]
+compile = "mvn compile"
+lint = "npm run lint"
+test = { run = "mvn test", depends = ["compile"] }
+ci = { depends = ["test", "lint"] }
+"#,
+        );
+        assert_eq!(
+            plan(&tasks, &["ci".to_string()]).unwrap(),
+            vec![
+                vec!["compile".to_string(), "lint".to_string()],
+                vec!["test".to_string()],
+                vec!["ci".to_string()],
+            ]
+        );
+
+        assert!(plan(&tasks, &["deploy".to_string()]).is_err());
+        let cyclic = self::tasks("[tasks]\na = { depends = [\"b\"] }\nb = { depends = [\"a\"] }\n");
+        let err = plan(&cyclic, &["a".to_string()]).unwrap_err().to_string();
+        assert!(err.contains("a -> b -> a"), "{}", err);
+    }
+}
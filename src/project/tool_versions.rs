@@ -0,0 +1,249 @@
+//! asdf / mise `.tool-versions` files: one tool per line, followed by one or more
+//! versions in order of preference (`java temurin-21.0.2+13.0.LTS 17`).
+
+use super::VersionRequest;
+use crate::core::constraint::VersionConstraint;
+use crate::error::Result;
+use crate::plugins::java::vendors;
+use std::fs;
+use std::path::Path;
+
+/// File name used by asdf and mise
+pub const TOOL_VERSIONS_FILE: &str = ".tool-versions";
+
+/// Read the requests in `dir/.tool-versions`, if the file exists
+pub fn load(dir: &Path) -> Result<Vec<VersionRequest>> {
+    let path = dir.join(TOOL_VERSIONS_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(&path)?;
+    Ok(parse(&contents)
+        .into_iter()
+        .map(|(tool_id, versions)| {
+            let mut versions = versions.into_iter();
+            let version = versions.next().unwrap_or_default();
+            VersionRequest::new(tool_id, version, &path).with_fallbacks(versions.collect())
+        })
+        .collect())
+}
+
+/// Parse `.tool-versions` contents into jcvm tool IDs and version requests
+pub fn parse(contents: &str) -> Vec<(String, Vec<String>)> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            let tool_id = tool_id(fields.next()?);
+            let versions: Vec<String> = fields
+                // "system" and "path:..." point outside of jcvm
+                .filter(|v| *v != "system" && !v.starts_with("path:"))
+                .map(|v| map_version(&tool_id, v))
+                .collect();
+            (!versions.is_empty()).then_some((tool_id, versions))
+        })
+        .collect()
+}
+
+/// Version to write to `.tool-versions` for a jcvm request, the reverse of [`map_version`].
+/// asdf can't read ranges, so those are written as the installed version they resolve to
+/// (`resolved`); Java versions get asdf-java distribution names.
+pub fn asdf_version(tool_id: &str, version: &str, resolved: &str) -> String {
+    let plain = VersionConstraint::parse(version).is_ok_and(|c| c.is_plain());
+    let version = if plain { version } else { resolved };
+    if tool_id != "java" {
+        return version.to_string();
+    }
+
+    let (vendor, number) = match version
+        .char_indices()
+        .find(|&(i, c)| c.is_ascii_digit() && (i == 0 || version[..i].ends_with('-')))
+        .map(|(i, _)| i)
+    {
+        Some(0) => (vendors::DEFAULT_VENDOR, version),
+        Some(idx) => match vendors::normalize_vendor(&version[..idx - 1]) {
+            Some(vendor) => (vendor, &version[idx..]),
+            None => return version.to_string(),
+        },
+        None => return version.to_string(),
+    };
+
+    match vendor {
+        "semeru" => format!("semeru-openj9-{}", number),
+        "graalvm" => format!("graalvm-community-{}", number),
+        vendor => format!("{}-{}", vendor, number),
+    }
+}
+
+/// Set the versions of a tool in `dir/.tool-versions`, keeping the other lines
+pub fn write_tool_version(dir: &Path, tool_id: &str, version: &str) -> Result<()> {
+    let path = dir.join(TOOL_VERSIONS_FILE);
+    let contents = if path.is_file() {
+        fs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
+
+    let entry = format!("{} {}", asdf_name(tool_id), version);
+    let mut replaced = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let name = line.split_whitespace().next().unwrap_or_default();
+            if !replaced && !name.starts_with('#') && self::tool_id(name) == tool_id {
+                replaced = true;
+                entry.clone()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(entry);
+    }
+
+    fs::write(&path, format!("{}\n", lines.join("\n")))?;
+    Ok(())
+}
+
+/// jcvm tool ID for an asdf plugin name
+fn tool_id(name: &str) -> String {
+    match name {
+        "nodejs" => "node".to_string(),
+        other => other.to_lowercase(),
+    }
+}
+
+/// asdf plugin name for a jcvm tool ID
+fn asdf_name(tool_id: &str) -> &str {
+    match tool_id {
+        "node" => "nodejs",
+        other => other,
+    }
+}
+
+/// Translate asdf-java names ("temurin-21.0.2+13.0.LTS", "corretto-21.0.2.13.1",
+/// "semeru-openj9-21.0.2+13_openj9-0.43.0") into jcvm requests ("temurin-21.0.2")
+fn map_version(tool_id: &str, version: &str) -> String {
+    if tool_id != "java" {
+        return version.to_string();
+    }
+
+    let Some(idx) = version
+        .char_indices()
+        .find(|&(i, c)| c.is_ascii_digit() && version[..i].ends_with('-'))
+        .map(|(i, _)| i)
+    else {
+        return version.to_string();
+    };
+    let distribution = version[..idx - 1].split('-').next().unwrap_or_default();
+    let number = version[idx..]
+        .split(['+', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(".LTS");
+
+    match vendors::normalize_vendor(distribution) {
+        // Zulu names carry Azul's own release number; only the feature release matches
+        Some("zulu") => format!("zulu-{}", number.split('.').next().unwrap_or(number)),
+        // Corretto appends build and revision numbers to the Java version
+        Some("corretto") => format!(
+            "corretto-{}",
+            number.split('.').take(3).collect::<Vec<_>>().join(".")
+        ),
+        Some(vendor) => format!("{}-{}", vendor, number),
+        // "openjdk-21" and other vendor-neutral names use the default vendor
+        None if distribution == "openjdk" => number.to_string(),
+        None => version.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_tools_and_fallback_versions() {
+        let contents = "# pinned tools\nnodejs 20.11.0 18.19.0\njava temurin-21.0.2+13.0.LTS\npython system\nruby 3.3.0 # comment\n";
+        assert_eq!(
+            parse(contents),
+            vec![
+                (
+                    "node".to_string(),
+                    vec!["20.11.0".to_string(), "18.19.0".to_string()]
+                ),
+                ("java".to_string(), vec!["temurin-21.0.2".to_string()]),
+                ("ruby".to_string(), vec!["3.3.0".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_versions_asdf_can_read() {
+        assert_eq!(asdf_version("java", "21", "21.0.2"), "temurin-21");
+        assert_eq!(
+            asdf_version("java", "corretto-17", "corretto-17.0.9"),
+            "corretto-17"
+        );
+        assert_eq!(
+            asdf_version("java", "ibm-21.0.2", "semeru-21.0.2"),
+            "semeru-openj9-21.0.2"
+        );
+        assert_eq!(
+            asdf_version("java", ">=17 <22", "temurin-21.0.2"),
+            "temurin-21.0.2"
+        );
+        assert_eq!(asdf_version("node", "^20", "20.11.0"), "20.11.0");
+        assert_eq!(asdf_version("python", "3.12", "3.12.1"), "3.12");
+
+        // Reading the name back gives the same request
+        for version in ["21", "corretto-17", "semeru-21.0.2", "zulu-21"] {
+            let asdf = asdf_version("java", version, version);
+            let request = map_version("java", &asdf);
+            assert!(
+                request == version || request == format!("temurin-{}", version),
+                "{} -> {} -> {}",
+                version,
+                asdf,
+                request
+            );
+        }
+    }
+
+    #[test]
+    fn maps_asdf_java_names() {
+        for (asdf, jcvm) in [
+            ("temurin-21.0.2+13.0.LTS", "temurin-21.0.2"),
+            ("adoptopenjdk-17.0.2+8", "temurin-17.0.2"),
+            ("corretto-21.0.2.13.1", "corretto-21.0.2"),
+            ("zulu-21.32.17", "zulu-21"),
+            ("semeru-openj9-21.0.2+13_openj9-0.43.0", "semeru-21.0.2"),
+            ("openjdk-21", "21"),
+            ("21", "21"),
+            ("graalvm-21.0.2", "graalvm-21.0.2"),
+        ] {
+            assert_eq!(map_version("java", asdf), jcvm, "{}", asdf);
+        }
+    }
+
+    #[test]
+    fn writes_tool_line_in_place() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(TOOL_VERSIONS_FILE),
+            "nodejs 18.19.0\npython 3.12.1\n",
+        )
+        .unwrap();
+
+        write_tool_version(dir.path(), "node", "20.11.0").unwrap();
+        write_tool_version(dir.path(), "java", "temurin-21").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join(TOOL_VERSIONS_FILE)).unwrap(),
+            "nodejs 20.11.0\npython 3.12.1\njava temurin-21\n"
+        );
+    }
+}
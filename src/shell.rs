@@ -1,6 +1,16 @@
 use crate::config::Config;
+use crate::core::env::{self, EnvAction, PATH_SEPARATOR};
 use crate::error::{JcvmError, Result};
-use std::path::PathBuf;
+use crate::project;
+use std::path::{Path, PathBuf};
+
+/// First line of the block `shell-init` writes to an rc file; the jcvm version follows
+const BLOCK_BEGIN: &str = "# >>> jcvm initialize";
+/// Last line of the block `shell-init` writes to an rc file
+const BLOCK_END: &str = "# <<< jcvm initialize <<<";
+/// First line of scripts installed before blocks were marked, which can't be removed
+/// automatically; the shell's `JCVM_DIR` assignment follows it
+const LEGACY_HEADER: &str = "# JCVM - Java Configuration & Version Manager\n";
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,10 +19,44 @@ pub enum Shell {
     Zsh,
     Fish,
     PowerShell,
+    Nushell,
+    Elvish,
+    Xonsh,
 }
 
 impl Shell {
+    /// Parse a shell name as given to `--shell`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            "powershell" | "pwsh" => Some(Shell::PowerShell),
+            "nu" | "nushell" => Some(Shell::Nushell),
+            "elvish" => Some(Shell::Elvish),
+            "xonsh" => Some(Shell::Xonsh),
+            _ => None,
+        }
+    }
+
+    /// The shell named by `--shell`, else the detected one (Bash if detection fails)
+    pub fn select(name: Option<&str>) -> Result<Self> {
+        match name {
+            Some(name) => Self::from_name(name)
+                .ok_or_else(|| JcvmError::ShellError(format!("Unsupported shell: {}", name))),
+            None => Ok(Self::detect().unwrap_or(Shell::Bash)),
+        }
+    }
+
     pub fn detect() -> Option<Self> {
+        // Nushell and Xonsh announce themselves to child processes; they are rarely $SHELL
+        if std::env::var_os("NU_VERSION").is_some() {
+            return Some(Shell::Nushell);
+        }
+        if std::env::var_os("XONSH_VERSION").is_some() {
+            return Some(Shell::Xonsh);
+        }
+
         // Try SHELL environment variable first
         if let Ok(shell) = std::env::var("SHELL") {
             if shell.contains("zsh") {
@@ -22,6 +66,14 @@ impl Shell {
             } else if shell.contains("fish") {
                 return Some(Shell::Fish);
             }
+
+            let name = std::path::Path::new(&shell)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if let Some(shell) = Self::from_name(&name) {
+                return Some(shell);
+            }
         }
 
         // Check for PowerShell on Windows
@@ -49,6 +101,9 @@ impl Shell {
             }
             Shell::Zsh => Some(home.join(".zshrc")),
             Shell::Fish => Some(home.join(".config/fish/config.fish")),
+            Shell::Nushell => Some(dirs::config_dir()?.join("nushell/config.nu")),
+            Shell::Elvish => Some(home.join(".config/elvish/rc.elv")),
+            Shell::Xonsh => Some(home.join(".xonshrc")),
             Shell::PowerShell => {
                 #[cfg(windows)]
                 {
@@ -60,58 +115,61 @@ impl Shell {
         }
     }
 
-    pub fn init_script(&self, jcvm_dir: &str) -> String {
+    /// Command that reloads the shell's config file after `shell-init` changed it
+    pub fn reload_hint(&self) -> &'static str {
+        match self {
+            Shell::Bash => "source ~/.bashrc",
+            Shell::Zsh => "source ~/.zshrc",
+            Shell::Fish => "source ~/.config/fish/config.fish",
+            Shell::PowerShell => ". $PROFILE",
+            Shell::Nushell => "exec nu",
+            Shell::Elvish => "exec elvish",
+            Shell::Xonsh => "source ~/.xonshrc",
+        }
+    }
+
+    /// Shell startup script. `activation` points each tool at its `current` alias (see
+    /// `ToolManager::current_environment`); directory changes then run `jcvm hook-env`.
+    pub fn init_script(&self, jcvm_dir: &str, activation: &[EnvAction]) -> String {
+        let activation = self.render_env(activation);
         match self {
             Shell::Bash | Shell::Zsh => format!(
                 r#"
 # JCVM - Java Configuration & Version Manager
 export JCVM_DIR="{}"
 
-# Add tool-specific bin directories to PATH
-# Priority order: python, node, java (current is legacy java)
-export PATH="$JCVM_DIR/alias/python/current/bin:$JCVM_DIR/alias/node/current/bin:$JCVM_DIR/alias/current/bin:$PATH"
-export JAVA_HOME="$JCVM_DIR/alias/current"
+# Current version of every tool (generated from the installed plugins)
+{activation}
 
-# Wrapper function for jcvm use to update current shell environment
-jcvm() {{
-    local command="$1"
-    shift
-    
-    case "$command" in
-        use)
-            # Call the actual jcvm binary and capture output
-            local jcvm_output
-            jcvm_output=$(command jcvm use "$@" 2>&1)
-            local exit_code=$?
-            
-            # Print the output
-            echo "$jcvm_output"
-            
-            # If successful, update the current shell environment
-            if [ $exit_code -eq 0 ]; then
-                export JAVA_HOME="$JCVM_DIR/alias/current"
-                # Rebuild PATH with all tool paths
-                # Remove old JCVM paths first
-                local cleaned_path=$(echo "$PATH" | tr ':' '\n' | grep -v "$JCVM_DIR" | tr '\n' ':' | sed 's/:$//')
-                export PATH="$JCVM_DIR/alias/python/current/bin:$JCVM_DIR/alias/node/current/bin:$JCVM_DIR/alias/current/bin:$cleaned_path"
-            fi
-            
-            return $exit_code
-            ;;
-        *)
-            # For all other commands, just pass through to the binary
-            command jcvm "$command" "$@"
-            ;;
-    esac
+# Whether this directory or a parent (up to $JCVM_PROJECT_ROOT) requests tool versions
+_jcvm_in_project() {{
+    local dir="$PWD" file
+    while [ -n "$dir" ]; do
+        for file in {markers}; do
+            [ -f "$dir/$file" ] && return 0
+        done
+        [ -n "$JCVM_PROJECT_ROOT" ] && [ "$dir" = "${{JCVM_PROJECT_ROOT%/}}" ] && return 1
+        [ "$dir" = "/" ] && return 1
+        dir="${{dir%/*}}"
+        [ -z "$dir" ] && dir="/"
+    done
+    return 1
 }}
 
-# Auto-switch JDK version on directory change
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
 _jcvm_auto_switch() {{
-    if [ -f ".java-version" ]; then
-        local version=$(cat .java-version | tr -d '[:space:]')
-        if [ -n "$version" ]; then
-            jcvm use "$version" >/dev/null 2>&1
-        fi
+    if _jcvm_in_project || [ -n "$__JCVM_HOOK_STATE" ]; then
+        eval "$(command jcvm hook-env --shell bash)"
+    fi
+}}
+
+# `jcvm shell` changes this shell's environment, so its output is evaluated
+jcvm() {{
+    if [ "$1" = "shell" ]; then
+        shift
+        eval "$(command jcvm shell --shell bash "$@")"
+    else
+        command jcvm "$@"
     fi
 }}
 
@@ -129,107 +187,93 @@ fi
 # Run on shell startup
 _jcvm_auto_switch
 "#,
-                jcvm_dir
+                jcvm_dir,
+                markers = project::MARKER_FILES.join(" ")
             ),
             Shell::Fish => format!(
                 r#"
 # JCVM - Java Configuration & Version Manager
 set -gx JCVM_DIR "{}"
-set -gx PATH "$JCVM_DIR/alias/current/bin" $PATH
-set -gx JAVA_HOME "$JCVM_DIR/alias/current"
 
-# Wrapper function for jcvm use to update current shell environment
-function jcvm
-    set -l command $argv[1]
-    set -e argv[1]
-    
-    switch $command
-        case use
-            # Call the actual jcvm binary and capture output
-            set -l jcvm_output (command jcvm use $argv 2>&1)
-            set -l exit_code $status
-            
-            # Print the output
-            echo $jcvm_output
-            
-            # If successful, update the current shell environment
-            if test $exit_code -eq 0
-                set -gx JAVA_HOME "$JCVM_DIR/alias/current"
-                # Remove old JAVA_HOME/bin from PATH if it exists
-                set -l new_path
-                for p in $PATH
-                    if not string match -q "*/bin" $p; or not string match -q "*java*" $p
-                        set new_path $new_path $p
-                    end
-                end
-                set -gx PATH "$JAVA_HOME/bin" $new_path
-            end
-            
-            return $exit_code
-        case '*'
-            # For all other commands, just pass through to the binary
-            command jcvm $command $argv
+# Current version of every tool (generated from the installed plugins)
+{activation}
+
+# Whether this directory or a parent (up to $JCVM_PROJECT_ROOT) requests tool versions
+function _jcvm_in_project
+    set -l dir $PWD
+    while true
+        for file in {markers}
+            test -f "$dir/$file"; and return 0
+        end
+        if test -n "$JCVM_PROJECT_ROOT"; and test "$dir" = (string trim -r -c / -- $JCVM_PROJECT_ROOT)
+            return 1
+        end
+        test "$dir" = /; and return 1
+        set dir (dirname $dir)
     end
 end
 
-# Auto-switch JDK version on directory change
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
 function _jcvm_auto_switch --on-variable PWD
-    if test -f .java-version
-        set version (cat .java-version | string trim)
-        if test -n "$version"
-            jcvm use "$version" >/dev/null 2>&1
-        end
+    if _jcvm_in_project; or set -q __JCVM_HOOK_STATE
+        command jcvm hook-env --shell fish | source
+    end
+end
+
+# `jcvm shell` changes this shell's environment, so its output is evaluated
+function jcvm
+    if test "$argv[1]" = shell
+        command jcvm shell --shell fish $argv[2..-1] | source
+    else
+        command jcvm $argv
     end
 end
 
 # Run on shell startup
 _jcvm_auto_switch
 "#,
-                jcvm_dir
+                jcvm_dir,
+                markers = project::MARKER_FILES.join(" ")
             ),
             Shell::PowerShell => format!(
                 r#"
 # JCVM - Java Configuration & Version Manager
 $env:JCVM_DIR = "{}"
-$env:PATH = "$env:JCVM_DIR\alias\current\bin;$env:PATH"
-$env:JAVA_HOME = "$env:JCVM_DIR\alias\current"
 
-# Wrapper function for jcvm use to update current shell environment
-function jcvm {{
-    $command = $args[0]
-    $restArgs = $args[1..($args.Length - 1)]
-    
-    if ($command -eq "use") {{
-        # Call the actual jcvm binary and capture output
-        $jcvmOutput = & jcvm.exe use $restArgs 2>&1 | Out-String
-        $exitCode = $LASTEXITCODE
-        
-        # Print the output
-        Write-Output $jcvmOutput
-        
-        # If successful, update the current shell environment
-        if ($exitCode -eq 0) {{
-            $env:JAVA_HOME = "$env:JCVM_DIR\alias\current"
-            # Remove old JAVA_HOME\bin from PATH if it exists
-            $pathParts = $env:PATH -split ';'
-            $newPath = $pathParts | Where-Object {{ $_ -notmatch 'java.*\\bin$' }}
-            $env:PATH = "$env:JAVA_HOME\bin;" + ($newPath -join ';')
+# Current version of every tool (generated from the installed plugins)
+{activation}
+
+# Whether this directory or a parent (up to $env:JCVM_PROJECT_ROOT) requests tool versions
+function Test-JcvmProject {{
+    $dir = $PWD.Path
+    while ($dir) {{
+        foreach ($file in @({ps_markers})) {{
+            if (Test-Path (Join-Path $dir $file)) {{ return $true }}
         }}
-        
-        exit $exitCode
-    }} else {{
-        # For all other commands, just pass through to the binary
-        & jcvm.exe $command $restArgs
+        if ($env:JCVM_PROJECT_ROOT -and $dir -eq $env:JCVM_PROJECT_ROOT.TrimEnd('\', '/')) {{
+            return $false
+        }}
+        $dir = Split-Path $dir -Parent
     }}
+    return $false
 }}
 
-# Auto-switch JDK version on directory change
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
 function Invoke-JcvmAutoSwitch {{
-    if (Test-Path .java-version) {{
-        $version = (Get-Content .java-version).Trim()
-        if ($version) {{
-            jcvm use $version 2>$null
-        }}
+    if ((Test-JcvmProject) -or $env:__JCVM_HOOK_STATE) {{
+        $script = & jcvm.exe hook-env --shell powershell | Out-String
+        if ($script.Trim()) {{ Invoke-Expression $script }}
+    }}
+}}
+
+# `jcvm shell` changes this shell's environment, so its output is evaluated
+function jcvm {{
+    if ($args.Count -gt 0 -and $args[0] -eq 'shell') {{
+        $rest = @($args | Select-Object -Skip 1)
+        $script = & jcvm.exe shell --shell powershell @rest | Out-String
+        if ($script.Trim()) {{ Invoke-Expression $script }}
+    }} else {{
+        & jcvm.exe @args
     }}
 }}
 
@@ -245,62 +289,492 @@ $ExecutionContext.InvokeCommand.LocationChangedAction = {{
 # Run on shell startup
 Invoke-JcvmAutoSwitch
 "#,
-                jcvm_dir
+                jcvm_dir,
+                ps_markers = project::MARKER_FILES
+                    .iter()
+                    .map(|file| format!("'{}'", file))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Shell::Nushell => format!(
+                r#"
+# JCVM - Java Configuration & Version Manager
+$env.JCVM_DIR = "{}"
+
+# Current version of every tool (generated from the installed plugins)
+{activation}
+
+# Nushell can't eval code, so hook-env and `jcvm shell` print a record of changes
+# (null = remove) that is applied here
+def --env _jcvm_apply [output: string] {{
+    let output = ($output | str trim)
+    if ($output | is-empty) {{ return }}
+    let changes = ($output | from json)
+    let removed = ($changes | columns | where {{|key| ($changes | get $key) == null }})
+    for key in $removed {{ hide-env -i $key }}
+    let updated = ($changes | reject ...$removed)
+    if "PATH" in ($updated | columns) {{
+        load-env ($updated | upsert PATH ($updated.PATH | split row (char esep)))
+    }} else {{
+        load-env $updated
+    }}
+}}
+
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
+def --env _jcvm_auto_switch [] {{
+    _jcvm_apply (^jcvm hook-env --shell nu)
+}}
+
+# Use a tool version in this shell only
+def --env "jcvm shell" [...args: string] {{
+    _jcvm_apply (^jcvm shell --shell nu ...$args)
+}}
+
+$env.config.hooks.env_change.PWD = (
+    $env.config.hooks.env_change.PWD? | default [] | append {{|before, after| _jcvm_auto_switch }}
+)
+
+# Run on shell startup
+_jcvm_auto_switch
+"#,
+                nu_quote(jcvm_dir)
+            ),
+            Shell::Elvish => format!(
+                r#"
+# JCVM - Java Configuration & Version Manager
+set-env JCVM_DIR {}
+
+# Current version of every tool (generated from the installed plugins)
+{activation}
+
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
+fn _jcvm_auto_switch {{
+    eval (e:jcvm hook-env --shell elvish | slurp)
+}}
+set after-chdir = [$@after-chdir {{|_| _jcvm_auto_switch }}]
+
+# `jcvm shell` changes this shell's environment, so its output is evaluated
+fn jcvm {{|@args|
+    if (and (> (count $args) 0) (eq $args[0] shell)) {{
+        eval (e:jcvm shell --shell elvish $@args[1..] | slurp)
+    }} else {{
+        e:jcvm $@args
+    }}
+}}
+
+# Run on shell startup
+_jcvm_auto_switch
+"#,
+                elvish_quote(jcvm_dir)
+            ),
+            Shell::Xonsh => format!(
+                r#"
+# JCVM - Java Configuration & Version Manager
+$JCVM_DIR = {}
+
+# Current version of every tool (generated from the installed plugins)
+{activation}
+
+import shutil as _jcvm_shutil
+_jcvm_exe = _jcvm_shutil.which("jcvm") or "jcvm"
+
+def _jcvm_eval(_jcvm_script):
+    if _jcvm_script.strip():
+        execx(_jcvm_script)
+
+# Apply the current directory's tool versions to this shell only, undoing them on leaving
+def _jcvm_auto_switch():
+    _jcvm_eval($(@(_jcvm_exe) hook-env --shell xonsh))
+
+# `jcvm shell` changes this shell's environment, so its output is evaluated
+def _jcvm(args):
+    if args and args[0] == "shell":
+        _jcvm_eval($(@(_jcvm_exe) shell --shell xonsh @(args[1:])))
+    else:
+        return ![@(_jcvm_exe) @(args)].returncode
+
+aliases["jcvm"] = _jcvm
+
+@events.on_chdir
+def _jcvm_on_chdir(olddir, newdir, **kw):
+    _jcvm_auto_switch()
+
+# Run on shell startup
+_jcvm_auto_switch()
+"#,
+                nu_quote(jcvm_dir)
             ),
         }
     }
 
-    pub fn install_hook(&self, config: &Config) -> Result<()> {
-        let config_file = self.config_file().ok_or_else(|| {
-            JcvmError::ShellError("Could not determine shell config file".to_string())
-        })?;
+    /// Init script wrapped in markers stamped with this jcvm's version, as written to rc files
+    pub fn init_block(&self, jcvm_dir: &str, activation: &[EnvAction]) -> String {
+        format!(
+            "{} (v{}) >>>\n{}\n{}\n",
+            BLOCK_BEGIN,
+            env!("CARGO_PKG_VERSION"),
+            self.init_script(jcvm_dir, activation).trim(),
+            BLOCK_END
+        )
+    }
 
-        // Check if already installed
-        if config_file.exists() {
-            let contents = std::fs::read_to_string(&config_file)?;
-            if contents.contains("JCVM_DIR") {
-                return Err(JcvmError::ShellError(
-                    "JCVM is already configured in your shell".to_string(),
-                ));
-            }
+    /// Append the init block to the shell's rc file
+    pub fn install_hook(&self, config: &Config, activation: &[EnvAction]) -> Result<PathBuf> {
+        let config_file = self.require_config_file()?;
+        let contents = read_if_exists(&config_file)?;
+
+        if let Some(block) = find_block(&contents)? {
+            return Err(JcvmError::ShellError(format!(
+                "JCVM {} is already configured in {}; run `jcvm shell-init --update` to refresh it",
+                block.version_label(),
+                config_file.display()
+            )));
+        }
+        if self.has_legacy_script(&contents) {
+            return Err(JcvmError::ShellError(format!(
+                "{} contains JCVM configuration from an older release; remove it and run `jcvm shell-init` again",
+                config_file.display()
+            )));
         }
 
-        // Create config file if it doesn't exist
-        if !config_file.exists() {
-            if let Some(parent) = config_file.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
-            std::fs::write(&config_file, "")?;
+        if let Some(parent) = config_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let block = self.init_block(config.jcvm_dir.to_str().unwrap(), activation);
+        std::fs::write(&config_file, append_block(&contents, &block))?;
+
+        Ok(config_file)
+    }
+
+    /// Replace the init block in the shell's rc file in place. Returns the file and the
+    /// version stamp of the block that was replaced.
+    pub fn update_hook(
+        &self,
+        config: &Config,
+        activation: &[EnvAction],
+    ) -> Result<(PathBuf, Option<String>)> {
+        let config_file = self.require_config_file()?;
+        let contents = read_if_exists(&config_file)?;
+        let block = find_block(&contents)?.ok_or_else(|| self.not_installed(&config_file))?;
+
+        let updated = format!(
+            "{}{}{}",
+            &contents[..block.start],
+            self.init_block(config.jcvm_dir.to_str().unwrap(), activation),
+            &contents[block.end..]
+        );
+        std::fs::write(&config_file, updated)?;
+
+        Ok((config_file, block.version))
+    }
+
+    /// Remove the init block from the shell's rc file
+    pub fn uninstall_hook(&self) -> Result<PathBuf> {
+        let config_file = self.require_config_file()?;
+        let contents = read_if_exists(&config_file)?;
+        let block = find_block(&contents)?.ok_or_else(|| self.not_installed(&config_file))?;
+        std::fs::write(&config_file, remove_block(&contents, &block))?;
+
+        Ok(config_file)
+    }
+
+    /// Whether shell integration is set up: the init script exports `JCVM_DIR` (also when
+    /// it is eval'd from `shell-init --print`), and installs leave a marked block in the rc file
+    pub fn integration_installed(shell: Option<Self>) -> bool {
+        if std::env::var_os("JCVM_DIR").is_some() {
+            return true;
         }
+        shell
+            .and_then(|shell| shell.config_file())
+            .and_then(|file| read_if_exists(&file).ok())
+            .is_some_and(|contents| matches!(find_block(&contents), Ok(Some(_))))
+    }
+
+    /// Whether `contents` holds the script an older release installed for this shell,
+    /// outside of a marked block
+    fn has_legacy_script(&self, contents: &str) -> bool {
+        let assignment = match self {
+            Shell::Bash | Shell::Zsh => "export JCVM_DIR",
+            Shell::Fish => "set -gx JCVM_DIR",
+            Shell::PowerShell => "$env:JCVM_DIR =",
+            // These shells were only supported once blocks were marked
+            Shell::Nushell | Shell::Elvish | Shell::Xonsh => return false,
+        };
+        // Current blocks start with the same header
+        let contents = contents.replace("\r\n", "\n");
+        let outside = match find_block(&contents) {
+            Ok(Some(block)) => remove_block(&contents, &block),
+            _ => contents,
+        };
+        outside.contains(&format!("{}{}", LEGACY_HEADER, assignment))
+    }
 
-        // Append init script
-        let init_script = self.init_script(config.jcvm_dir.to_str().unwrap());
-        let mut contents = std::fs::read_to_string(&config_file)?;
-        contents.push_str(&init_script);
-        std::fs::write(&config_file, contents)?;
+    fn require_config_file(&self) -> Result<PathBuf> {
+        self.config_file().ok_or_else(|| {
+            JcvmError::ShellError("Could not determine shell config file".to_string())
+        })
+    }
 
-        Ok(())
+    fn not_installed(&self, config_file: &Path) -> JcvmError {
+        let hint = if read_if_exists(config_file).is_ok_and(|c| self.has_legacy_script(&c)) {
+            " (it contains configuration from an older release, which must be removed by hand)"
+        } else {
+            ""
+        };
+        JcvmError::ShellError(format!(
+            "JCVM is not configured in {}{}",
+            config_file.display(),
+            hint
+        ))
     }
 
-    pub fn use_command(&self, java_home: &str) -> String {
+    /// Render the changes computed by `hook-env`. Nushell can't eval code, so it gets a JSON
+    /// record of the final values instead (`null` means remove).
+    pub fn render_hook_env(&self, changes: &[EnvAction]) -> String {
         match self {
-            Shell::Bash | Shell::Zsh | Shell::Fish => format!(
-                r#"export JAVA_HOME="{}"
-export PATH="$JAVA_HOME/bin:$PATH""#,
-                java_home
+            Shell::Nushell => {
+                let record: serde_json::Map<String, serde_json::Value> =
+                    env::resolve(changes, |key| std::env::var(key).ok())
+                        .into_iter()
+                        .map(|(key, value)| {
+                            (key, value.map_or(serde_json::Value::Null, Into::into))
+                        })
+                        .collect();
+                serde_json::Value::Object(record).to_string()
+            }
+            _ => self.render_env(changes),
+        }
+    }
+
+    /// Render environment actions as commands for this shell
+    pub fn render_env(&self, actions: &[EnvAction]) -> String {
+        actions
+            .iter()
+            .map(|action| self.render_action(action))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_action(&self, action: &EnvAction) -> String {
+        match (self, action) {
+            (Shell::Bash | Shell::Zsh, EnvAction::Set { key, value }) => {
+                format!(r#"export {}="{}""#, key, posix_escape(value))
+            }
+            (Shell::Bash | Shell::Zsh, EnvAction::PrependPath { key, path }) => format!(
+                r#"export {key}="{}${{{key}:+:${key}}}""#,
+                posix_escape(&path.display().to_string())
             ),
-            Shell::PowerShell => format!(
-                r#"$env:JAVA_HOME = "{}"
-$env:PATH = "$env:JAVA_HOME\bin;$env:PATH""#,
-                java_home
+            (Shell::Bash | Shell::Zsh, EnvAction::AppendPath { key, path }) => format!(
+                r#"export {key}="${{{key}:+${key}:}}{}""#,
+                posix_escape(&path.display().to_string())
+            ),
+            (Shell::Bash | Shell::Zsh, EnvAction::Unset { key }) => format!("unset {}", key),
+            (Shell::Fish, EnvAction::Set { key, value }) => {
+                format!(r#"set -gx {} "{}""#, key, fish_escape(value))
+            }
+            (Shell::Fish, EnvAction::PrependPath { key, path }) => format!(
+                r#"set -gx {key} "{}" ${key}"#,
+                fish_escape(&path.display().to_string())
+            ),
+            (Shell::Fish, EnvAction::AppendPath { key, path }) => format!(
+                r#"set -gx {key} ${key} "{}""#,
+                fish_escape(&path.display().to_string())
+            ),
+            (Shell::Fish, EnvAction::Unset { key }) => format!("set -e {}", key),
+            (Shell::PowerShell, EnvAction::Set { key, value }) => {
+                format!("$env:{} = '{}'", key, value.replace('\'', "''"))
+            }
+            (Shell::PowerShell, EnvAction::PrependPath { key, path }) => format!(
+                "$env:{key} = '{}' + [IO.Path]::PathSeparator + $env:{key}",
+                path.display().to_string().replace('\'', "''")
             ),
+            (Shell::PowerShell, EnvAction::AppendPath { key, path }) => format!(
+                "$env:{key} = $env:{key} + [IO.Path]::PathSeparator + '{}'",
+                path.display().to_string().replace('\'', "''")
+            ),
+            (Shell::PowerShell, EnvAction::Unset { key }) => {
+                format!("Remove-Item Env:{} -ErrorAction SilentlyContinue", key)
+            }
+            (Shell::Nushell, EnvAction::Set { key, value }) => {
+                format!("$env.{} = {}", key, nu_quote(value))
+            }
+            // PATH is a list in Nushell; other path variables stay strings
+            (Shell::Nushell, EnvAction::PrependPath { key, path }) if key == "PATH" => format!(
+                "$env.PATH = ($env.PATH | prepend {})",
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Nushell, EnvAction::AppendPath { key, path }) if key == "PATH" => format!(
+                "$env.PATH = ($env.PATH | append {})",
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Nushell, EnvAction::PrependPath { key, path }) => format!(
+                "$env.{key} = ([{}] | append ($env.{key}? | default '' | split row (char esep) | compact --empty) | str join (char esep))",
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Nushell, EnvAction::AppendPath { key, path }) => format!(
+                "$env.{key} = ($env.{key}? | default '' | split row (char esep) | compact --empty | append {} | str join (char esep))",
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Nushell, EnvAction::Unset { key }) => format!("hide-env -i {}", key),
+            (Shell::Elvish, EnvAction::Set { key, value }) => {
+                format!("set-env {} {}", key, elvish_quote(value))
+            }
+            (Shell::Elvish, EnvAction::PrependPath { key, path }) => {
+                let path = path.display().to_string();
+                format!(
+                    "if (has-env {key}) {{ set-env {key} {}(get-env {key}) }} else {{ set-env {key} {} }}",
+                    elvish_quote(&format!("{}{}", path, PATH_SEPARATOR)),
+                    elvish_quote(&path)
+                )
+            }
+            (Shell::Elvish, EnvAction::AppendPath { key, path }) => {
+                let path = path.display().to_string();
+                format!(
+                    "if (has-env {key}) {{ set-env {key} (get-env {key}){} }} else {{ set-env {key} {} }}",
+                    elvish_quote(&format!("{}{}", PATH_SEPARATOR, path)),
+                    elvish_quote(&path)
+                )
+            }
+            (Shell::Elvish, EnvAction::Unset { key }) => format!("unset-env {}", key),
+            (Shell::Xonsh, EnvAction::Set { key, value }) => {
+                format!("${} = {}", key, nu_quote(value))
+            }
+            // Xonsh keeps *PATH variables as lists
+            (Shell::Xonsh, EnvAction::PrependPath { key, path }) => format!(
+                r#"${{...}}["{key}"] = [{}] + list(${{...}}.get("{key}", []))"#,
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Xonsh, EnvAction::AppendPath { key, path }) => format!(
+                r#"${{...}}["{key}"] = list(${{...}}.get("{key}", [])) + [{}]"#,
+                nu_quote(&path.display().to_string())
+            ),
+            (Shell::Xonsh, EnvAction::Unset { key }) => {
+                format!(r#"${{...}}.pop("{}", None)"#, key)
+            }
+        }
+    }
+}
+
+/// Escape a value for use inside double quotes in POSIX shells
+pub fn posix_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escape a value for use inside double quotes in fish
+fn fish_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | '$') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Location of a marked init block within an rc file
+#[derive(Debug, PartialEq, Eq)]
+struct Block {
+    start: usize,
+    /// Just past the end marker's line break
+    end: usize,
+    version: Option<String>,
+}
+
+impl Block {
+    fn version_label(&self) -> String {
+        self.version
+            .as_ref()
+            .map_or_else(|| "(unknown version)".to_string(), |v| format!("v{}", v))
+    }
+}
+
+/// Find the init block in an rc file's contents
+fn find_block(contents: &str) -> Result<Option<Block>> {
+    let Some(start) = contents.find(BLOCK_BEGIN) else {
+        return Ok(None);
+    };
+    let begin_line = contents[start..].lines().next().unwrap_or_default();
+    let version = begin_line
+        .split_once("(v")
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map(|(version, _)| version.to_string());
+
+    let end = contents[start..]
+        .find(BLOCK_END)
+        .map(|offset| start + offset + BLOCK_END.len())
+        .ok_or_else(|| {
+            JcvmError::ShellError(format!(
+                "JCVM block is missing its end marker `{}`; fix the file by hand",
+                BLOCK_END
+            ))
+        })?;
+    let end = if contents[end..].starts_with("\r\n") {
+        end + 2
+    } else if contents[end..].starts_with('\n') {
+        end + 1
+    } else {
+        end
+    };
+
+    Ok(Some(Block {
+        start,
+        end,
+        version,
+    }))
+}
+
+/// Append a block to an rc file, separated from what's there by a blank line
+fn append_block(contents: &str, block: &str) -> String {
+    let mut contents = contents.to_string();
+    if !contents.is_empty() {
+        if !contents.ends_with('\n') {
+            contents.push('\n');
         }
+        contents.push('\n');
+    }
+    contents.push_str(block);
+    contents
+}
+
+/// Remove a block, and the blank line `append_block` put before it
+fn remove_block(contents: &str, block: &Block) -> String {
+    let before = &contents[..block.start];
+    let before = match before.strip_suffix('\n') {
+        Some(trimmed) if trimmed.is_empty() || trimmed.ends_with('\n') => trimmed,
+        _ => before,
+    };
+    format!("{}{}", before, &contents[block.end..])
+}
+
+fn read_if_exists(path: &Path) -> Result<String> {
+    if path.exists() {
+        Ok(std::fs::read_to_string(path)?)
+    } else {
+        Ok(String::new())
     }
 }
 
-pub fn generate_activation_script(version_path: &str) -> Result<String> {
+/// Double-quoted string for Nushell and Xonsh (JSON string escapes are valid in both)
+fn nu_quote(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// Single-quoted string for Elvish, where only `'` needs escaping (as `''`)
+fn elvish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+pub fn generate_activation_script(actions: &[EnvAction]) -> Result<String> {
     let shell = Shell::detect().unwrap_or(Shell::Bash);
-    Ok(shell.use_command(version_path))
+    Ok(shell.render_env(actions))
 }
 
 #[cfg(test)]
@@ -317,8 +791,135 @@ mod tests {
     #[test]
     fn test_init_script_generation() {
         let shell = Shell::Bash;
-        let script = shell.init_script("/home/user/.jcvm");
+        let script = shell.init_script("/home/user/.jcvm", &[]);
         assert!(script.contains("JCVM_DIR"));
         assert!(script.contains("_jcvm_auto_switch"));
+        assert!(script.contains("jcvm hook-env --shell bash"));
+        assert!(script.contains("eval \"$(command jcvm shell --shell bash \"$@\")\""));
+        assert!(script.contains("for file in jcvm.toml .tool-versions"));
+    }
+
+    #[test]
+    fn init_scripts_activate_every_tool() {
+        let activation = vec![
+            EnvAction::set("JAVA_HOME", "/home/user/.jcvm/alias/java/current"),
+            EnvAction::set("NODE_HOME", "/home/user/.jcvm/alias/node/current"),
+            EnvAction::prepend_path("PATH", "/home/user/.jcvm/alias/node/current/bin"),
+        ];
+
+        let fish = Shell::Fish.init_script("/home/user/.jcvm", &activation);
+        assert!(fish.contains("set -gx NODE_HOME \"/home/user/.jcvm/alias/node/current\""));
+        assert!(fish.contains("set -gx PATH \"/home/user/.jcvm/alias/node/current/bin\" $PATH"));
+        assert!(fish.contains("command jcvm hook-env --shell fish | source"));
+
+        let powershell = Shell::PowerShell.init_script("/home/user/.jcvm", &activation);
+        assert!(powershell.contains("$env:JAVA_HOME = '/home/user/.jcvm/alias/java/current'"));
+        assert!(powershell.contains("hook-env --shell powershell"));
+    }
+
+    #[test]
+    fn finds_marked_blocks_and_their_version() {
+        let block = Shell::Zsh.init_block("/home/user/.jcvm", &[]);
+        assert!(block.starts_with(&format!(
+            "{} (v{}) >>>\n",
+            BLOCK_BEGIN,
+            env!("CARGO_PKG_VERSION")
+        )));
+
+        let rc = format!("alias ll='ls -l'\n\n{}export EDITOR=vim\n", block);
+        let found = find_block(&rc).unwrap().unwrap();
+        assert_eq!(found.version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        assert_eq!(&rc[..found.start], "alias ll='ls -l'\n\n");
+        assert_eq!(&rc[found.end..], "export EDITOR=vim\n");
+
+        let old =
+            "# >>> jcvm initialize (v2.0.0) >>>\nexport JCVM_DIR=x\n# <<< jcvm initialize <<<";
+        let found = find_block(old).unwrap().unwrap();
+        assert_eq!(found.version.as_deref(), Some("2.0.0"));
+        assert_eq!(found.end, old.len());
+
+        assert_eq!(find_block("export PATH=/bin\n").unwrap(), None);
+        for original in ["", "export PATH=/bin", "export PATH=/bin\n"] {
+            let installed = append_block(original, &block);
+            let found = find_block(&installed).unwrap().unwrap();
+            let expected = if original.is_empty() || original.ends_with('\n') {
+                original.to_string()
+            } else {
+                format!("{}\n", original)
+            };
+            assert_eq!(remove_block(&installed, &found), expected);
+        }
+        assert!(find_block("# >>> jcvm initialize (v2.0.0) >>>\nexport JCVM_DIR=x\n").is_err());
+    }
+
+    #[test]
+    fn recognises_scripts_from_older_releases() {
+        let fish = "set -g fish_greeting\n\n# JCVM - Java Configuration & Version Manager\nset -gx JCVM_DIR \"/home/user/.jcvm\"\nset -gx PATH \"$JCVM_DIR/alias/current/bin\" $PATH\n";
+        assert!(Shell::Fish.has_legacy_script(fish));
+        assert!(!Shell::Fish.has_legacy_script("set -gx JCVM_DIR /opt/jcvm\n"));
+        assert!(!Shell::Fish.has_legacy_script(&Shell::Fish.init_block("/home/user/.jcvm", &[])));
+
+        let bash =
+            "# JCVM - Java Configuration & Version Manager\nexport JCVM_DIR=\"/home/user/.jcvm\"\n";
+        assert!(Shell::Bash.has_legacy_script(bash));
+        let powershell =
+            "# JCVM - Java Configuration & Version Manager\r\n$env:JCVM_DIR = \"C:\\jcvm\"\r\n";
+        assert!(Shell::PowerShell.has_legacy_script(powershell));
+    }
+
+    #[test]
+    fn parses_and_renders_additional_shells() {
+        assert_eq!(Shell::from_name("nu"), Some(Shell::Nushell));
+        assert_eq!(Shell::from_name("Elvish"), Some(Shell::Elvish));
+        assert_eq!(Shell::from_name("xonsh"), Some(Shell::Xonsh));
+
+        let changes = vec![
+            EnvAction::set("JAVA_HOME", "/opt/jdk"),
+            EnvAction::unset("NODE_HOME"),
+        ];
+        assert_eq!(
+            Shell::Nushell.render_hook_env(&changes),
+            r#"{"JAVA_HOME":"/opt/jdk","NODE_HOME":null}"#
+        );
+        assert_eq!(Shell::Nushell.render_hook_env(&[]), "{}");
+
+        for shell in [Shell::Nushell, Shell::Elvish, Shell::Xonsh] {
+            let script = shell.init_script("/home/user/.jcvm", &[]);
+            assert!(script.contains("hook-env --shell"), "{:?}", shell);
+        }
+    }
+
+    #[test]
+    fn renders_env_actions_per_shell() {
+        let actions = vec![
+            EnvAction::set("JAVA_HOME", "/opt/jdk"),
+            EnvAction::prepend_path("PATH", "/opt/jdk/bin"),
+            EnvAction::unset("JRE_HOME"),
+        ];
+
+        assert_eq!(
+            Shell::Bash.render_env(&actions),
+            "export JAVA_HOME=\"/opt/jdk\"\nexport PATH=\"/opt/jdk/bin${PATH:+:$PATH}\"\nunset JRE_HOME"
+        );
+        assert_eq!(
+            Shell::Fish.render_env(&actions),
+            "set -gx JAVA_HOME \"/opt/jdk\"\nset -gx PATH \"/opt/jdk/bin\" $PATH\nset -e JRE_HOME"
+        );
+        assert_eq!(
+            Shell::PowerShell.render_env(&actions[1..2]),
+            "$env:PATH = '/opt/jdk/bin' + [IO.Path]::PathSeparator + $env:PATH"
+        );
+        assert_eq!(
+            Shell::Nushell.render_env(&actions),
+            "$env.JAVA_HOME = \"/opt/jdk\"\n$env.PATH = ($env.PATH | prepend \"/opt/jdk/bin\")\nhide-env -i JRE_HOME"
+        );
+        assert_eq!(
+            Shell::Elvish.render_env(&[actions[0].clone(), actions[2].clone()]),
+            "set-env JAVA_HOME '/opt/jdk'\nunset-env JRE_HOME"
+        );
+        assert_eq!(
+            Shell::Xonsh.render_env(&actions[..2]),
+            "$JAVA_HOME = \"/opt/jdk\"\n${...}[\"PATH\"] = [\"/opt/jdk/bin\"] + list(${...}.get(\"PATH\", []))"
+        );
     }
 }
@@ -0,0 +1,124 @@
+//! Shim executables: small scripts in `~/.jcvm/shims` that hand each call to
+//! `jcvm shim-exec`, which picks the tool version for the caller's working directory.
+
+use crate::error::Result;
+use crate::shell::posix_escape;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Marker line identifying files jcvm generated, so stale shims can be removed safely
+const SHIM_MARKER: &str = "jcvm shim";
+
+/// Name of the shim for an executable: its file name, without `.exe`/`.cmd` on Windows
+pub fn shim_name(executable: &Path) -> Option<String> {
+    let name = if cfg!(windows) {
+        executable.file_stem()?
+    } else {
+        executable.file_name()?
+    };
+    Some(name.to_string_lossy().to_string())
+}
+
+/// Write one shim per entry of `shims` (executable name → tool ID) into `dir`, removing
+/// shims for executables no installed version provides anymore
+pub fn write_shims(dir: &Path, jcvm: &Path, shims: &BTreeMap<String, String>) -> Result<()> {
+    fs::create_dir_all(dir)?;
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let stale = shim_name(&path).is_none_or(|name| !shims.contains_key(&name));
+        let generated = fs::read_to_string(&path).is_ok_and(|c| c.contains(SHIM_MARKER));
+        if stale && generated {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    for (name, tool_id) in shims {
+        let path = if cfg!(windows) {
+            dir.join(format!("{}.cmd", name))
+        } else {
+            dir.join(name)
+        };
+        fs::write(&path, shim_script(jcvm, tool_id, name))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn shim_script(jcvm: &Path, tool_id: &str, name: &str) -> String {
+    let jcvm = jcvm.display().to_string();
+    if cfg!(windows) {
+        format!(
+            "@echo off\r\nrem {marker} for {name} ({tool_id})\r\n\"{jcvm}\" shim-exec --tool \"{tool}\" \"{exe}\" %*\r\n",
+            marker = SHIM_MARKER,
+            jcvm = cmd_escape(&jcvm),
+            tool = cmd_escape(tool_id),
+            exe = cmd_escape(name),
+        )
+    } else {
+        format!(
+            "#!/bin/sh\n# {marker} for {name} ({tool_id})\nexec \"{jcvm}\" shim-exec --tool \"{tool}\" \"{exe}\" \"$@\"\n",
+            marker = SHIM_MARKER,
+            jcvm = posix_escape(&jcvm),
+            tool = posix_escape(tool_id),
+            exe = posix_escape(name),
+        )
+    }
+}
+
+/// Escape a value for use inside double quotes in a `.cmd` script: `%` would expand
+/// variables, and a doubled `"` keeps cmd inside the quotes
+fn cmd_escape(value: &str) -> String {
+    value.replace('%', "%%").replace('"', "\"\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn writes_shims_and_removes_stale_ones() {
+        let dir = TempDir::new().unwrap();
+        let shims_dir = dir.path().join("shims");
+        let jcvm = Path::new("/usr/local/bin/jcvm");
+
+        let mut shims = BTreeMap::new();
+        shims.insert("java".to_string(), "java".to_string());
+        shims.insert("npm".to_string(), "node".to_string());
+        write_shims(&shims_dir, jcvm, &shims).unwrap();
+
+        let npm = shims_dir.join(if cfg!(windows) { "npm.cmd" } else { "npm" });
+        let script = fs::read_to_string(&npm).unwrap();
+        assert!(script.contains("\"/usr/local/bin/jcvm\" shim-exec --tool \"node\" \"npm\""));
+
+        // Files the user put there are kept; shims nothing provides anymore are removed
+        fs::write(shims_dir.join("custom"), "#!/bin/sh\n").unwrap();
+        shims.remove("npm");
+        write_shims(&shims_dir, jcvm, &shims).unwrap();
+        assert!(!npm.exists());
+        assert!(shims_dir.join("custom").exists());
+    }
+
+    #[test]
+    fn quotes_the_jcvm_path() {
+        let jcvm = Path::new("/home/me/$HOME `x` \"q\"/100%/jcvm");
+        let script = shim_script(jcvm, "node", "npm");
+        if cfg!(windows) {
+            assert!(script.contains("\"/home/me/$HOME `x` \"\"q\"\"/100%%/jcvm\" shim-exec"));
+        } else {
+            assert!(script.contains(r#"exec "/home/me/\$HOME \`x\` \"q\"/100%/jcvm" shim-exec"#));
+        }
+        assert_eq!(
+            cmd_escape(r#"C:\Users\100% "me"\jcvm.exe"#),
+            r#"C:\Users\100%% ""me""\jcvm.exe"#
+        );
+    }
+}
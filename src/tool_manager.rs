@@ -1,12 +1,16 @@
 use crate::config::Config;
+use crate::core::constraint::VersionConstraint;
+use crate::core::env::EnvAction;
 use crate::core::plugin::PluginRegistry;
 use crate::core::traits::{
-    Architecture, InstalledTool, Platform, PluginMetadata, ToolInfo, ToolPlugin, ToolVersion,
+    Architecture, InstalledTool, Platform, PluginMetadata, ToolInfo, ToolPlugin, ToolProvider,
+    ToolVersion,
 };
 use crate::error::{JcvmError, Result};
+use crate::shims;
 use chrono::{DateTime, Utc};
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::io::{BufReader, BufWriter};
 use std::path::{Path, PathBuf};
@@ -45,7 +49,7 @@ pub struct ActivationContext {
     pub version: ToolVersion,
     pub install_path: PathBuf,
     pub home_path: PathBuf,
-    pub env: Vec<(String, String)>,
+    pub env: Vec<EnvAction>,
 }
 
 impl ToolManager {
@@ -70,6 +74,16 @@ impl ToolManager {
         plugin.list_remote_versions(lts_only).await
     }
 
+    /// List every remote release of one major version, newest first
+    pub async fn list_remote_releases(
+        &self,
+        tool_id: &str,
+        major: u32,
+    ) -> Result<Vec<ToolVersion>> {
+        let plugin = self.plugin(tool_id)?;
+        plugin.list_remote_releases(major).await
+    }
+
     pub async fn install(
         &self,
         tool_id: &str,
@@ -77,15 +91,41 @@ impl ToolManager {
         force: bool,
     ) -> Result<InstalledTool> {
         let plugin = self.plugin(tool_id)?;
-        let version = plugin.parse_version(version_str)?;
-        let dest_dir = self.config.tool_version_dir(tool_id, &version.raw)?;
+        let resolved = self
+            .resolve_remote_version(tool_id, &plugin, version_str)
+            .await?;
+        let version = plugin.parse_version(&resolved)?;
+
+        let requested_dir = self.config.tool_version_dir(tool_id, &version.raw)?;
+        if requested_dir.exists() && !force {
+            return Err(JcvmError::VersionAlreadyInstalled(
+                version.raw.clone(),
+                requested_dir.display().to_string(),
+            ));
+        }
+
+        let (platform, arch) = Self::detect_platform()?;
+        if !plugin.supports_platform(platform, arch) {
+            return Err(JcvmError::UnsupportedPlatform {
+                os: platform.to_string(),
+                arch: arch.to_string(),
+            });
+        }
+
+        let distribution = plugin.find_distribution(&version, platform, arch).await?;
+
+        // Providers may resolve a more specific version (e.g. "21" -> "temurin-21.0.4+7"),
+        // which determines the install directory
+        let dest_dir = self
+            .config
+            .tool_version_dir(tool_id, &distribution.version.raw)?;
 
         if dest_dir.exists() {
             if force {
-                self.uninstall(tool_id, &version.raw).await?;
+                self.uninstall(tool_id, &distribution.version.raw).await?;
             } else {
                 return Err(JcvmError::VersionAlreadyInstalled(
-                    version.raw.clone(),
+                    distribution.version.raw.clone(),
                     dest_dir.display().to_string(),
                 ));
             }
@@ -95,17 +135,9 @@ impl ToolManager {
             fs::create_dir_all(parent)?;
         }
 
-        let (platform, arch) = Self::detect_platform()?;
-        if !plugin.supports_platform(platform, arch) {
-            return Err(JcvmError::UnsupportedPlatform {
-                os: platform.to_string(),
-                arch: arch.to_string(),
-            });
-        }
-
-        let distribution = plugin.find_distribution(&version, platform, arch).await?;
         let installed = plugin.install(&distribution, &dest_dir).await?;
         self.write_manifest(&installed)?;
+        self.refresh_shims();
         Ok(installed)
     }
 
@@ -150,6 +182,7 @@ impl ToolManager {
         plugin.uninstall(&installed).await?;
         let _ = fs::remove_file(self.manifest_path(&install_dir));
         self.cleanup_aliases(tool_id, &install_dir)?;
+        self.refresh_shims();
         Ok(())
     }
 
@@ -189,6 +222,7 @@ impl ToolManager {
 
         // Write the manifest
         self.write_manifest(&installed)?;
+        self.refresh_shims();
 
         Ok(installed)
     }
@@ -338,6 +372,69 @@ impl ToolManager {
         })
     }
 
+    /// Environment changes needed to use an installed version
+    pub fn environment(&self, tool_id: &str, version_str: &str) -> Result<Vec<EnvAction>> {
+        let plugin = self.plugin(tool_id)?;
+        let install_dir = self.resolve_install_dir(tool_id, version_str)?;
+        plugin.get_environment_vars(&install_dir)
+    }
+
+    /// Environment pointing every registered tool at its `current` alias, for shell startup
+    /// scripts. Tools whose plugin can't describe it are left out.
+    pub fn current_environment(&self) -> Result<Vec<EnvAction>> {
+        let mut env = Vec::new();
+        for tool_id in self.registry.list_plugins()? {
+            let plugin = self.plugin(&tool_id)?;
+            let current = self.config.tool_current_symlink(&tool_id)?;
+            match plugin.get_environment_vars(&current) {
+                Ok(vars) => env.extend(vars),
+                Err(e) => warn!("Skipping {} in shell integration: {}", tool_id, e),
+            }
+        }
+        Ok(env)
+    }
+
+    /// Path of the executable `name` in an installed version
+    pub fn executable(&self, tool_id: &str, version_str: &str, name: &str) -> Result<PathBuf> {
+        let plugin = self.plugin(tool_id)?;
+        let install_dir = self.resolve_install_dir(tool_id, version_str)?;
+        plugin
+            .get_executable_paths(&install_dir)?
+            .into_iter()
+            .find(|path| shims::shim_name(path).as_deref() == Some(name) && path.exists())
+            .ok_or_else(|| JcvmError::InvalidToolStructure {
+                tool: tool_id.to_string(),
+                message: format!("{} {} does not provide {}", tool_id, version_str, name),
+            })
+    }
+
+    /// Regenerate the shims directory from the executables of every installed version.
+    /// Returns the shims written (executable name → tool ID).
+    pub fn reshim(&self) -> Result<BTreeMap<String, String>> {
+        let mut shims = BTreeMap::new();
+        for installation in self.list_installed(None)? {
+            let plugin = self.plugin(&installation.tool_id)?;
+            for path in plugin.get_executable_paths(&installation.path)? {
+                if let Some(name) = shims::shim_name(&path).filter(|_| path.exists()) {
+                    shims
+                        .entry(name)
+                        .or_insert_with(|| installation.tool_id.clone());
+                }
+            }
+        }
+
+        let jcvm = std::env::current_exe()?;
+        shims::write_shims(&self.config.shims_dir(), &jcvm, &shims)?;
+        Ok(shims)
+    }
+
+    /// Keep shims in step with installs; a failure here must not fail the install itself
+    fn refresh_shims(&self) {
+        if let Err(e) = self.reshim() {
+            warn!("Failed to update shims: {}", e);
+        }
+    }
+
     pub fn set_alias(&self, tool_id: &str, alias: &str, version_str: &str) -> Result<()> {
         let plugin = self.plugin(tool_id)?;
         let install_dir = self.resolve_install_dir(tool_id, version_str)?;
@@ -413,6 +510,7 @@ impl ToolManager {
 
         for tool_id in tool_ids {
             let plugin = self.plugin(&tool_id)?;
+            let first = results.len();
             let versions_root = self.config.tool_versions_dir(&tool_id);
             let current_link = self.config.tool_current_symlink(&tool_id)?;
             let default_link = self.config.tool_default_symlink(&tool_id)?;
@@ -478,13 +576,12 @@ impl ToolManager {
                     &mut results,
                 )?;
             }
-        }
 
-        results.sort_by(|a, b| {
-            a.tool_id
-                .cmp(&b.tool_id)
-                .then_with(|| Self::compare_versions_desc(&a.version, &b.version))
-        });
+            // Tools are visited in order, so only this tool's entries need sorting
+            results[first..].sort_by(|a, b| {
+                Self::compare_versions_desc(plugin.as_ref(), &a.version, &b.version)
+            });
+        }
 
         Ok(results)
     }
@@ -580,20 +677,87 @@ impl ToolManager {
         install_dir.join(MANIFEST_FILE)
     }
 
-    /// Find the best matching installed version for a given version prefix.
+    /// Resolve a version request to the name of an installed version.
+    ///
+    /// Accepts exact versions as well as constraint expressions such as
+    /// `>=17 <22`, `^20.10`, `~3.12`, `21.x` or `lts`.
+    pub fn resolve_installed_version(
+        &self,
+        tool_id: &str,
+        request: &str,
+    ) -> Result<Option<String>> {
+        match self.resolve_install_dir(tool_id, request) {
+            Ok(path) => Ok(path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())),
+            Err(JcvmError::VersionNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Resolve a version request against the versions available remotely.
+    ///
+    /// Fully specified versions are returned unchanged. Partial versions and
+    /// constraint expressions resolve to the highest matching remote version;
+    /// a partial version with no remote match is passed through so plugins can
+    /// still handle it themselves (e.g. Java feature releases like "21").
+    async fn resolve_remote_version(
+        &self,
+        tool_id: &str,
+        plugin: &Arc<dyn ToolPlugin>,
+        request: &str,
+    ) -> Result<String> {
+        // Pre-release requests ("24-ea") go straight to the plugin: remote listings
+        // only cover releases
+        let constraint = match VersionConstraint::parse(request) {
+            Ok(c) if !c.is_exact() && c.pre_release().is_none() => c,
+            _ => return Ok(request.to_string()),
+        };
+
+        let remote = match plugin.list_remote_versions(constraint.is_lts()).await {
+            Ok(versions) => versions,
+            Err(_) if constraint.is_plain() => return Ok(request.to_string()),
+            Err(e) => return Err(e),
+        };
+
+        // Providers that don't list versions per vendor are matched on the version alone,
+        // keeping the requested vendor prefix (e.g. "corretto->=17 <22" -> "corretto-21")
+        let vendorless_listing = remote.iter().all(|v| v.vendor.is_none());
+        let matcher = if vendorless_listing {
+            constraint.without_vendor()
+        } else {
+            constraint.clone()
+        };
+
+        match matcher.best_match_by(&remote, |a, b| plugin.compare_versions(a, b)) {
+            Some(version) => match constraint.vendor() {
+                Some(vendor) if version.vendor.is_none() => {
+                    Ok(format!("{}-{}", vendor, version.raw))
+                }
+                _ => Ok(version.raw.clone()),
+            },
+            None if constraint.is_plain() => Ok(request.to_string()),
+            None => Err(JcvmError::VersionNotFound(format!(
+                "{}@{}",
+                tool_id, request
+            ))),
+        }
+    }
+
+    /// Find the best matching installed version for a given version constraint.
     ///
     /// This is a generic feature that works for all plugins by default.
     /// Each plugin's `parse_version` method is used to validate candidates.
     ///
     /// # Examples
-    /// - Python: "3.13" → "3.13.7", "3.10" → "3.10.18" (highest patch)
-    /// - Node.js: "22" → "22.19.0", "18" → "18.20.8"
-    /// - Java: "21" → "21.0.7", "1.8" → "1.8.0_452"
+    /// - Python: "3.13" → "3.13.7", "~3.10" → "3.10.18" (highest patch)
+    /// - Node.js: "22" → "22.19.0", "^18" → "18.20.8", "lts" → highest LTS
+    /// - Java: "21.x" → "21.0.7", ">=17 <21" → "17.0.12"
     ///
     /// # Returns
     /// - `Ok(Some(version_string))` if a matching version is found
     /// - `Ok(None)` if no matching version exists
-    fn find_matching_version(&self, tool_id: &str, version_prefix: &str) -> Result<Option<String>> {
+    fn find_matching_version(&self, tool_id: &str, request: &str) -> Result<Option<String>> {
         let plugin = self.plugin(tool_id)?;
         let versions_root = self.config.tool_versions_dir(tool_id);
 
@@ -601,6 +765,11 @@ impl ToolManager {
             return Ok(None);
         }
 
+        let constraint = match VersionConstraint::parse(request) {
+            Ok(c) => c,
+            Err(_) => return Ok(None),
+        };
+
         let mut candidates = Vec::new();
 
         for entry in fs::read_dir(&versions_root)? {
@@ -613,10 +782,9 @@ impl ToolManager {
 
             let folder = entry.file_name().to_string_lossy().to_string();
 
-            // Check if this folder starts with the version prefix
-            if folder.starts_with(version_prefix) {
-                // Try to parse as a version to validate it
-                if let Ok(parsed_version) = plugin.parse_version(&folder) {
+            // Try to parse as a version to validate it
+            if let Ok(parsed_version) = plugin.parse_version(&folder) {
+                if constraint.matches(&parsed_version) {
                     candidates.push((folder, parsed_version));
                 }
             }
@@ -625,7 +793,7 @@ impl ToolManager {
         // If we have candidates, sort them and return the best match
         if !candidates.is_empty() {
             // Sort by version in descending order (highest version first)
-            candidates.sort_by(|(_, a), (_, b)| Self::compare_versions_desc(a, b));
+            candidates.sort_by(|(_, a), (_, b)| Self::compare_versions_desc(plugin.as_ref(), a, b));
 
             // Return the highest matching version
             Ok(Some(candidates[0].0.clone()))
@@ -640,7 +808,7 @@ impl ToolManager {
             return Ok(primary);
         }
 
-        // Try fuzzy matching for partial versions (e.g., "3.13" -> "3.13.7")
+        // Try constraint matching for partial versions and ranges (e.g., "3.13" -> "3.13.7")
         if let Ok(Some(matched_version)) = self.find_matching_version(tool_id, version) {
             let matched_path = self.config.tool_version_dir(tool_id, &matched_version)?;
             if matched_path.exists() {
@@ -760,63 +928,22 @@ impl ToolManager {
         &self,
         plugin: &Arc<dyn ToolPlugin>,
         install_dir: &Path,
-    ) -> Result<(PathBuf, Vec<(String, String)>)> {
+    ) -> Result<(PathBuf, Vec<EnvAction>)> {
         let install_path = install_dir.to_path_buf();
         let env = plugin.get_environment_vars(&install_path)?;
         let home = Self::extract_home_path(&env, install_dir);
         Ok((home, env))
     }
 
-    fn extract_home_path(env: &[(String, String)], install_dir: &Path) -> PathBuf {
-        for (key, value) in env.iter() {
-            if key.ends_with("_HOME") {
-                let cleaned = Self::sanitize_home_value(value);
-                if !cleaned.is_empty() {
-                    return PathBuf::from(cleaned);
+    fn extract_home_path(env: &[EnvAction], install_dir: &Path) -> PathBuf {
+        env.iter()
+            .find_map(|action| match action {
+                EnvAction::Set { key, value } if key.ends_with("_HOME") && !value.is_empty() => {
+                    Some(PathBuf::from(value))
                 }
-            }
-        }
-        install_dir.to_path_buf()
-    }
-
-    fn sanitize_home_value(value: &str) -> String {
-        let mut cleaned = value.trim().to_string();
-
-        for delimiter in ['$', '%'] {
-            if let Some(idx) = cleaned.find(delimiter) {
-                cleaned.truncate(idx);
-            }
-        }
-
-        for separator in [':', ';'] {
-            if let Some(idx) = Self::find_separator_index(&cleaned, separator) {
-                cleaned.truncate(idx);
-            }
-        }
-
-        cleaned = cleaned.trim().to_string();
-        cleaned.trim_end_matches(['/', '\\']).trim().to_string()
-    }
-
-    fn find_separator_index(value: &str, separator: char) -> Option<usize> {
-        if separator != ':' {
-            return value.find(separator);
-        }
-
-        value
-            .char_indices()
-            .find(|(idx, ch)| *ch == ':' && !Self::is_drive_colon(value, *idx))
-            .map(|(idx, _)| idx)
-    }
-
-    fn is_drive_colon(value: &str, idx: usize) -> bool {
-        if idx != 1 {
-            return false;
-        }
-
-        let bytes = value.as_bytes();
-        matches!(bytes.first(), Some(b) if b.is_ascii_alphabetic())
-            && matches!(bytes.get(2), Some(b'\\' | b'/'))
+                _ => None,
+            })
+            .unwrap_or_else(|| install_dir.to_path_buf())
     }
 
     fn collect_legacy_java(
@@ -879,12 +1006,12 @@ impl ToolManager {
             .map(DateTime::<Utc>::from)
     }
 
-    fn compare_versions_desc(a: &ToolVersion, b: &ToolVersion) -> Ordering {
-        b.major
-            .cmp(&a.major)
-            .then(b.minor.unwrap_or(0).cmp(&a.minor.unwrap_or(0)))
-            .then(b.patch.unwrap_or(0).cmp(&a.patch.unwrap_or(0)))
-            .then(b.raw.cmp(&a.raw))
+    fn compare_versions_desc<P: ToolProvider + ?Sized>(
+        plugin: &P,
+        a: &ToolVersion,
+        b: &ToolVersion,
+    ) -> Ordering {
+        plugin.compare_versions(b, a)
     }
 
     fn links_point_to(&self, links: &[PathBuf], install_dir: &Path) -> Result<bool> {
@@ -903,18 +1030,25 @@ mod tests {
     use tempfile::TempDir;
 
     #[test]
-    fn sanitize_home_value_strips_env_and_path_segments() {
-        let input = "/opt/jcvm/java/21/bin:$PATH";
-        let sanitized = ToolManager::sanitize_home_value(input);
-        assert_eq!(sanitized, "/opt/jcvm/java/21/bin".to_string());
-
-        let input_win = "C:\\jcvm\\java\\21\\bin;%PATH%";
-        let sanitized_win = ToolManager::sanitize_home_value(input_win);
-        assert_eq!(sanitized_win, "C:\\jcvm\\java\\21\\bin".to_string());
+    fn extract_home_path_uses_home_variable() {
+        let install_dir = Path::new("/opt/jcvm/java/21");
+        let env = vec![
+            EnvAction::prepend_path("PATH", "/opt/jcvm/java/21/Contents/Home/bin"),
+            EnvAction::set("JAVA_HOME", "/opt/jcvm/java/21/Contents/Home"),
+        ];
+        assert_eq!(
+            ToolManager::extract_home_path(&env, install_dir),
+            PathBuf::from("/opt/jcvm/java/21/Contents/Home")
+        );
+        assert_eq!(
+            ToolManager::extract_home_path(&env[..1], install_dir),
+            install_dir
+        );
     }
 
     #[test]
     fn test_version_comparison() {
+        let plugin = crate::plugins::java::JavaPlugin::new();
         let v3_10_10 = ToolVersion::new("3.10.10".to_string(), 3, Some(10), Some(10));
         let v3_10_18 = ToolVersion::new("3.10.18".to_string(), 3, Some(10), Some(18));
         let v3_13_7 = ToolVersion::new("3.13.7".to_string(), 3, Some(13), Some(7));
@@ -922,21 +1056,39 @@ mod tests {
         // compare_versions_desc compares b to a (b.cmp(a)), so it sorts descending
         // When v3_10_10 is 'a' and v3_10_18 is 'b', we expect Greater (b > a)
         assert_eq!(
-            ToolManager::compare_versions_desc(&v3_10_10, &v3_10_18),
+            ToolManager::compare_versions_desc(&plugin, &v3_10_10, &v3_10_18),
             Ordering::Greater
         );
         assert_eq!(
-            ToolManager::compare_versions_desc(&v3_10_18, &v3_10_10),
+            ToolManager::compare_versions_desc(&plugin, &v3_10_18, &v3_10_10),
             Ordering::Less
         );
         assert_eq!(
-            ToolManager::compare_versions_desc(&v3_10_18, &v3_13_7),
+            ToolManager::compare_versions_desc(&plugin, &v3_10_18, &v3_13_7),
             Ordering::Greater
         );
         assert_eq!(
-            ToolManager::compare_versions_desc(&v3_10_10, &v3_10_10),
+            ToolManager::compare_versions_desc(&plugin, &v3_10_10, &v3_10_10),
             Ordering::Equal
         );
+
+        // Builds and pre-releases take part in the ordering
+        let build7 = ToolVersion::new("21.0.3+7".to_string(), 21, Some(0), Some(3))
+            .with_build("7".to_string());
+        let build9 = ToolVersion::new("21.0.3+9".to_string(), 21, Some(0), Some(3))
+            .with_build("9".to_string());
+        assert_eq!(
+            ToolManager::compare_versions_desc(&plugin, &build7, &build9),
+            Ordering::Greater
+        );
+
+        let rc1 = ToolVersion::new("3.13.0rc1".to_string(), 3, Some(13), Some(0))
+            .with_pre_release("rc1".to_string());
+        let v3_13_0 = ToolVersion::new("3.13.0".to_string(), 3, Some(13), Some(0));
+        assert_eq!(
+            ToolManager::compare_versions_desc(&plugin, &rc1, &v3_13_0),
+            Ordering::Greater
+        );
     }
 
     #[tokio::test]
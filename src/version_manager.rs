@@ -161,22 +161,15 @@ impl VersionManager {
         Ok(version)
     }
 
-    /// Read version from .java-version file
+    /// Read version from the nearest .java-version file in the current directory or its
+    /// parents (up to `JCVM_PROJECT_ROOT`)
     pub fn read_local_version() -> Result<Option<Version>> {
-        let java_version_file = std::path::Path::new(".java-version");
-
-        if !java_version_file.exists() {
+        let cwd = std::env::current_dir()?;
+        let Some(request) = crate::project::find_version_file(&cwd, "java")? else {
             return Ok(None);
-        }
-
-        let contents = std::fs::read_to_string(java_version_file)?;
-        let version_str = contents.trim();
-
-        if version_str.is_empty() {
-            return Ok(None);
-        }
+        };
 
-        let version = version_str.parse::<Version>()?;
+        let version = request.version.parse::<Version>()?;
         Ok(Some(version))
     }
 